@@ -0,0 +1,42 @@
+//! Generates the solution registry of the `all` binary from the day modules in `src/bin`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    // day modules are named like `2025-01.rs`.
+    let mut days: Vec<(String, String)> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let (year, day) = name.strip_suffix(".rs")?.split_once('-')?;
+            let is_digits =
+                |s: &str, len: usize| s.len() == len && s.chars().all(|c| c.is_ascii_digit());
+            (is_digits(year, 4) && is_digits(day, 2)).then(|| (year.to_string(), day.to_string()))
+        })
+        .collect();
+    days.sort();
+
+    let mut registry = String::new();
+
+    for (year, day) in &days {
+        let path = bin_dir.join(format!("{year}-{day}.rs"));
+        registry.push_str(&format!(
+            "#[path = {:?}]\nmod day_{year}_{day};\n",
+            path.display().to_string()
+        ));
+    }
+
+    registry.push_str("\n/// Every scaffolded solution, sorted by year and day.\n");
+    registry
+        .push_str("pub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for (year, day) in &days {
+        registry.push_str(&format!("    day_{year}_{day}::SOLUTION,\n"));
+    }
+    registry.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs");
+    fs::write(out_path, registry).unwrap();
+}
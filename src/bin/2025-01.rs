@@ -1,36 +1,40 @@
 advent_of_code::solution!(1);
 
-fn get_rotation(turn: &str) -> i32 {
+fn get_rotation(turn: &str) -> Result<i32, String> {
+    let distance = |s: &str| {
+        s.parse::<i32>()
+            .map_err(|e| format!("Received invalid turn distance in `{turn}`: {e}"))
+    };
     match turn.chars().next() {
-        Some('R') => turn[1..].parse::<i32>().unwrap(),
-        Some('L') => -1 * turn[1..].parse::<i32>().unwrap(),
-        _ => panic!("Received invalid turn direction"),
+        Some('R') => distance(&turn[1..]),
+        Some('L') => distance(&turn[1..]).map(|x| -x),
+        _ => Err(format!("Received invalid turn direction in `{turn}`")),
     }
 }
 
-pub fn part_one(input: &str) -> Option<i64> {
+pub fn part_one(input: &str) -> Result<i64, String> {
     let mut count = 0;
     let mut lock_value = 50;
 
     for turn in input.lines() {
-        let rotation = get_rotation(turn);
+        let rotation = get_rotation(turn)?;
         lock_value = (lock_value + rotation).rem_euclid(100);
         if lock_value == 0 {
             count += 1;
         }
     }
-    Some(count)
+    Ok(count)
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
+pub fn part_two(input: &str) -> Result<i32, String> {
     let mut count = 0;
     let mut lock_value = 50;
 
     for turn in input.lines() {
-        let mut rotation = get_rotation(turn);
+        let mut rotation = get_rotation(turn)?;
 
         count += rotation.abs().div_euclid(100); // count the full turns
-        rotation = rotation % 100; // get net rotation
+        rotation %= 100; // get net rotation
 
         // if we moved past 0 on the net rotation add 1 to counter
         if ((lock_value + rotation) <= 0 && lock_value != 0) || (lock_value + rotation) >= 100 {
@@ -38,7 +42,7 @@ pub fn part_two(input: &str) -> Option<i32> {
         }
         lock_value = (lock_value + rotation).rem_euclid(100); // update position
     }
-    Some(count)
+    Ok(count)
 }
 
 #[cfg(test)]
@@ -47,13 +51,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(6));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(6));
     }
 }
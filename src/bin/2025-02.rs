@@ -0,0 +1,78 @@
+use advent_of_code::interval_set::IntervalSet;
+use std::ops::RangeInclusive;
+
+advent_of_code::solution!(2);
+
+/// All IDs in the range that consist of a sequence of digits repeated a number of times accepted by
+/// `repeats`, e.g. `123123` for 2 times.
+fn repeated_ids(range: RangeInclusive<u64>, repeats: impl Fn(u32) -> bool) -> Vec<u64> {
+    let (start, end) = range.into_inner();
+    let max_digits = end.checked_ilog10().unwrap_or(0) + 1;
+    let mut ids = Vec::new();
+
+    for digits in 2..=max_digits {
+        for unit_digits in (1..digits).filter(|x| digits.is_multiple_of(*x)) {
+            if !repeats(digits / unit_digits) {
+                continue;
+            }
+
+            // e.g. 1001 for a unit of 2 digits repeated 2 times, so that 12 * 1001 = 1212.
+            // this stays below `u64::MAX` for up to 20 digits, the most a `u64` has.
+            let multiplier: u64 = (0..digits / unit_digits)
+                .map(|x| 10u64.pow(x * unit_digits))
+                .sum();
+
+            // only the units whose ID lies in the range, so that no ID exceeds `end`.
+            let first = start.div_ceil(multiplier).max(10u64.pow(unit_digits - 1));
+            let last = (end / multiplier).min(10u64.pow(unit_digits) - 1);
+            ids.extend((first..=last).map(|unit| unit * multiplier));
+        }
+    }
+
+    ids
+}
+
+fn get_invalid_sum(input: &str, repeats: impl Fn(u32) -> bool) -> u64 {
+    let ranges: IntervalSet<u64> = input.parse().unwrap();
+    let mut ids: Vec<u64> = ranges
+        .iter()
+        .flat_map(|range| repeated_ids(range, &repeats))
+        .collect();
+
+    // IDs like 1111 repeat both `1` and `11`.
+    ids.sort_unstable();
+    ids.dedup();
+    ids.into_iter().sum()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    Some(get_invalid_sum(input, |times| times == 2))
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    Some(get_invalid_sum(input, |times| times >= 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(1227775554));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(4174379265));
+    }
+
+    #[test]
+    fn test_largest_ids() {
+        let input = "18446744071844674407-18446744073709551615";
+        assert_eq!(part_one(input), Some(18446744071844674407));
+        assert_eq!(part_two(input), Some(18446744071844674407));
+    }
+}
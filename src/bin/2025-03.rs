@@ -1,12 +1,10 @@
 advent_of_code::solution!(3);
 
-fn bucket_brigade(bank: &mut Vec<u32>, new_digit: u32) {
+fn bucket_brigade(bank: &mut [u32], new_digit: u32) {
     let mut old = new_digit;
-    for i in 0..bank.len() {
-        if bank[i] <= old {
-            let temp = old;
-            old = bank[i];
-            bank[i] = temp;
+    for battery in bank.iter_mut() {
+        if *battery <= old {
+            std::mem::swap(&mut old, battery);
         } else {
             break;
         }
@@ -20,10 +18,7 @@ fn get_bank(input: &str, num_batteries: usize) -> u64 {
             let digits: Vec<u32> = s.chars().map(|c| c.to_digit(10).unwrap()).collect();
 
             // Create battery bank
-            let mut bank: Vec<u32> = Vec::new();
-            for i in s.len() - num_batteries..s.len() {
-                bank.push(digits[i]);
-            }
+            let mut bank: Vec<u32> = digits[s.len() - num_batteries..].to_vec();
 
             for digit in digits[0..s.len() - num_batteries].iter().rev() {
                 if *digit >= bank[0] {
@@ -52,13 +47,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(357));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3121910778619));
     }
 }
@@ -0,0 +1,66 @@
+use advent_of_code::grid::Grid;
+
+advent_of_code::solution!(4, parser: parse);
+
+const ROLL: char = '@';
+
+fn parse(input: &str) -> Grid<char> {
+    input.parse().unwrap()
+}
+
+/// The rolls that fewer than 4 neighboring rolls block, so that a forklift can reach them.
+fn get_accessible_rolls(grid: &Grid<char>) -> Vec<(usize, usize)> {
+    grid.iter()
+        .filter(|&((row, col), &cell)| {
+            cell == ROLL
+                && grid
+                    .neighbors8(row, col)
+                    .filter(|&pos| grid[pos] == ROLL)
+                    .count()
+                    < 4
+        })
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+pub fn part_one(input: &Grid<char>) -> Option<u64> {
+    Some(get_accessible_rolls(input).len() as u64)
+}
+
+pub fn part_two(input: &Grid<char>) -> Option<u64> {
+    let mut grid = input.clone();
+
+    let mut total_count: u64 = 0;
+    loop {
+        let removed_rolls = get_accessible_rolls(&grid);
+        if removed_rolls.is_empty() {
+            break;
+        }
+        total_count += removed_rolls.len() as u64;
+        for pos in removed_rolls {
+            grid[pos] = '.';
+        }
+    }
+    Some(total_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
+        assert_eq!(result, Some(43));
+    }
+}
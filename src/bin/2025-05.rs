@@ -0,0 +1,52 @@
+use advent_of_code::interval_set::IntervalSet;
+
+advent_of_code::solution!(5, parser: parse);
+
+pub struct Ingredients {
+    fresh: IntervalSet<u64>,
+    ids: Vec<u64>,
+}
+
+fn parse(input: &str) -> Ingredients {
+    let (ranges, ids) = input.split_once("\n\n").unwrap();
+
+    Ingredients {
+        fresh: ranges.parse().unwrap(),
+        ids: ids.lines().map(|line| line.parse().unwrap()).collect(),
+    }
+}
+
+pub fn part_one(ingredients: &Ingredients) -> Option<u64> {
+    Some(
+        ingredients
+            .ids
+            .iter()
+            .filter(|&&id| ingredients.fresh.contains(id))
+            .count() as u64,
+    )
+}
+
+pub fn part_two(ingredients: &Ingredients) -> Option<u64> {
+    Some(ingredients.fresh.covered_len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
+        assert_eq!(result, Some(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
+        assert_eq!(result, Some(14));
+    }
+}
@@ -16,7 +16,7 @@ impl fmt::Display for Worksheet {
                     write!(f, "{}", self.numbers[i][j])?;
                 }
             }
-            write!(f, "\n")?;
+            writeln!(f)?;
         }
         Ok(())
     }
@@ -81,8 +81,8 @@ fn parse_pt2(input: &str) -> Worksheet {
     let mut numbers: Vec<u64> = Vec::new();
     for j in 0..max_length {
         let mut number: u64 = 0;
-        for i in 0..char_2d_array.len() {
-            let digit = char_2d_array[i][j].to_digit(10);
+        for row in &char_2d_array {
+            let digit = row[j].to_digit(10);
             match digit {
                 Some(x) => number = number * 10 + x as u64,
                 None => continue,
@@ -133,13 +133,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4277556));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(3263827));
     }
 }
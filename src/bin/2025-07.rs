@@ -1,4 +1,4 @@
-use memoize::memoize;
+use advent_of_code::memo::Memo;
 use std::collections::HashSet;
 
 advent_of_code::solution!(7);
@@ -11,7 +11,7 @@ struct Manifold {
 fn parse(input: &str) -> Manifold {
     let mut lines = input.lines();
     let mut beams: HashSet<usize> = HashSet::new();
-    beams.insert(lines.next().unwrap().find(|c| c == 'S').unwrap());
+    beams.insert(lines.next().unwrap().find('S').unwrap());
     let splitters: Vec<HashSet<usize>> = lines
         .skip(1)
         .step_by(2)
@@ -24,16 +24,22 @@ fn parse(input: &str) -> Manifold {
     Manifold { beams, splitters }
 }
 
-#[memoize(Ignore: splitters)]
-fn count_timelines(row: usize, col: usize, splitters: &Vec<HashSet<usize>>) -> u64 {
-    if row == splitters.len() {
-        return 1;
-    }
-    if splitters[row].contains(&col) {
-        return count_timelines(row + 1, col - 1, splitters)
-            + count_timelines(row + 1, col + 1, splitters);
-    }
-    return count_timelines(row + 1, col, splitters);
+fn count_timelines(
+    memo: &mut Memo<[HashSet<usize>], (usize, usize), u64>,
+    row: usize,
+    col: usize,
+) -> u64 {
+    memo.get_or_insert_with((row, col), |memo, &(row, col)| {
+        let splitters = memo.input();
+        if row == splitters.len() {
+            return 1;
+        }
+        if splitters[row].contains(&col) {
+            return count_timelines(memo, row + 1, col - 1)
+                + count_timelines(memo, row + 1, col + 1);
+        }
+        count_timelines(memo, row + 1, col)
+    })
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
@@ -54,9 +60,9 @@ pub fn part_one(input: &str) -> Option<u64> {
 pub fn part_two(input: &str) -> Option<u64> {
     let mut manifold = parse(input);
     let num_timelines = count_timelines(
+        &mut Memo::new(&manifold.splitters[..]),
         0,
         manifold.beams.drain().next().unwrap(),
-        &manifold.splitters,
     );
     Some(num_timelines)
 }
@@ -67,13 +73,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(40));
     }
 }
@@ -0,0 +1,73 @@
+use advent_of_code::geometry::{self, Point3};
+use advent_of_code::template::params::Params;
+use advent_of_code::union_find::{Union, UnionFind};
+
+advent_of_code::solution!(8, parser: parse, params);
+
+type Point = Point3<i64>;
+
+fn parse(input: &str) -> Vec<Point> {
+    geometry::parse_lines(input).unwrap()
+}
+
+/// All pairs of junction boxes, closest first.
+fn sorted_connections(points: &[Point]) -> Vec<(usize, usize)> {
+    let mut connections: Vec<(i64, usize, usize)> = Vec::new();
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            connections.push((points[i].distance_squared(points[j]), i, j));
+        }
+    }
+    connections.sort_unstable();
+    connections.into_iter().map(|(_, i, j)| (i, j)).collect()
+}
+
+pub fn part_one(points: &[Point], params: &Params) -> Option<u64> {
+    let num_connections: usize = params.get("num_connections").unwrap_or(1000);
+
+    let mut circuits = UnionFind::new(points.len());
+    for (pt1, pt2) in sorted_connections(points).into_iter().take(num_connections) {
+        circuits.union(pt1, pt2);
+    }
+
+    Some(
+        circuits
+            .largest(3)
+            .into_iter()
+            .map(|size| size as u64)
+            .product(),
+    )
+}
+
+pub fn part_two(points: &[Point], _params: &Params) -> Option<u64> {
+    let mut circuits = UnionFind::new(points.len());
+
+    let (pt1, pt2) = sorted_connections(points)
+        .into_iter()
+        .find(|&(pt1, pt2)| circuits.union(pt1, pt2) == Union::FullyConnected)?;
+
+    Some((points[pt1].x * points[pt2].x).try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(
+            &parse(&advent_of_code::template::read_file("examples", YEAR, DAY)),
+            &advent_of_code::template::read_params("examples", YEAR, DAY),
+        );
+        assert_eq!(result, Some(40));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(
+            &parse(&advent_of_code::template::read_file("examples", YEAR, DAY)),
+            &advent_of_code::template::read_params("examples", YEAR, DAY),
+        );
+        assert_eq!(result, Some(25272));
+    }
+}
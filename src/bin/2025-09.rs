@@ -0,0 +1,95 @@
+use advent_of_code::geometry::{self, Box2, Point2};
+use std::cmp;
+
+advent_of_code::solution!(9);
+
+type Point = Point2<u64>;
+type Rectangle = Box2<u64>;
+
+/// Whether the border runs through the inside of the rectangle, rather than along or outside of its edges.
+fn crosses_inside(rectangle: &Rectangle, border: &(Point, Point)) -> bool {
+    let (min, max) = (rectangle.min, rectangle.max);
+    let above_or_below = (border.0.y <= min.y && border.1.y <= min.y)
+        || (border.0.y >= max.y && border.1.y >= max.y); // Border is fully above or below rectangle
+    let left_or_right = (border.0.x <= min.x && border.1.x <= min.x)
+        || (border.0.x >= max.x && border.1.x >= max.x); // Border is fully left or right of rectangle
+    !(left_or_right || above_or_below) // Border is inside rectangle
+}
+
+fn parse(input: &str) -> Vec<Point> {
+    geometry::parse_lines(input).unwrap()
+}
+
+fn generate_rectangles(points: &[Point]) -> Vec<Rectangle> {
+    let mut rectangles: Vec<Rectangle> = Vec::new();
+    for i in 0..points.len() {
+        let pt1 = &points[i];
+        for pt2 in points.iter().skip(i + 1) {
+            rectangles.push(Rectangle::from_corners(*pt1, *pt2));
+        }
+    }
+    rectangles
+}
+
+fn valid_rectangle(rectangle: &Rectangle, borders: &[(Point, Point)]) -> bool {
+    borders
+        .iter()
+        .filter(|border| crosses_inside(rectangle, border))
+        .peekable()
+        .peek()
+        .is_none() // No borders inside rectangle
+}
+
+fn max_valid_area(rectangles: &[Rectangle], borders: &[(Point, Point)]) -> Option<u64> {
+    // Assume rectangles is sorted s.t. the largest area is at index 0
+    for rectangle in rectangles.iter() {
+        if valid_rectangle(rectangle, borders) {
+            return Some(rectangle.area());
+        }
+    }
+    None
+}
+
+fn generate_borders(points: &[Point]) -> Vec<(Point, Point)> {
+    let mut borders: Vec<(Point, Point)> = Vec::new();
+    for i in 1..points.len() {
+        let pt1 = points[i - 1];
+        let pt2 = points[i];
+        borders.push((pt1, pt2));
+    }
+    borders.push((*points.last().unwrap(), points[0]));
+    borders
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let points: Vec<Point> = parse(input);
+    let mut rectangles: Vec<Rectangle> = generate_rectangles(&points);
+    rectangles.sort_by_key(|rectangle| cmp::Reverse(rectangle.area()));
+    Some(rectangles.first().unwrap().area())
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let points: Vec<Point> = parse(input);
+    let mut rectangles: Vec<Rectangle> = generate_rectangles(&points);
+    rectangles.sort_by_key(|rectangle| cmp::Reverse(rectangle.area()));
+    let borders: Vec<(Point, Point)> = generate_borders(&points);
+
+    max_valid_area(&rectangles, &borders)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(50));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(24));
+    }
+}
@@ -0,0 +1,155 @@
+use advent_of_code::{gf2, linear};
+
+advent_of_code::solution!(10, parser: parse);
+
+pub struct Machine {
+    lights: Vec<usize>,
+    buttons: Vec<Vec<usize>>,
+    joltages: Vec<usize>,
+}
+
+impl Machine {
+    /// The matrix over GF(2) with one row per light and one column per button that toggles it.
+    fn lights_matrix(&self) -> gf2::Matrix {
+        let mut matrix = gf2::Matrix::zeros(self.lights.len(), self.buttons.len());
+        for (col, button) in self.buttons.iter().enumerate() {
+            for &row in button.iter() {
+                matrix.set(row, col, true);
+            }
+        }
+        matrix
+    }
+
+    /// The fewest button presses that switch on exactly the lit lights.
+    fn fewest_light_presses(&self) -> u64 {
+        let target: gf2::BitVec = self.lights.iter().map(|&on| on == 1).collect();
+        self.lights_matrix()
+            .min_weight_solution(&target)
+            .expect("lights cannot be reached")
+            .weight() as u64
+    }
+
+    /// The coefficients with one row per counter and one column per button that increases it.
+    fn joltage_matrix(&self) -> Vec<Vec<u64>> {
+        let mut matrix = vec![vec![0; self.buttons.len()]; self.joltages.len()];
+        for (col, button) in self.buttons.iter().enumerate() {
+            for &row in button.iter() {
+                matrix[row][col] = 1;
+            }
+        }
+        matrix
+    }
+
+    /// The fewest button presses that raise every counter to exactly its joltage.
+    fn fewest_joltage_presses(&self) -> u64 {
+        let joltages: Vec<u64> = self.joltages.iter().map(|&x| x as u64).collect();
+        linear::min_sum_solution(&self.joltage_matrix(), &joltages)
+            .expect("joltages cannot be reached")
+            .iter()
+            .sum()
+    }
+}
+
+fn parse(input: &str) -> Vec<Machine> {
+    input
+        .lines()
+        .map(|s| {
+            let mut parts = s.split_whitespace();
+            let lights: Vec<usize> = parts
+                .next()
+                .unwrap()
+                .trim_matches(|c| c == '[' || c == ']')
+                .chars()
+                .map(|c| if c == '#' { 1 } else { 0 })
+                .collect();
+            let mut buttons: Vec<Vec<usize>> = parts
+                .clone()
+                .rev()
+                .skip(1)
+                .map(|button| {
+                    button
+                        .trim_matches(|c| c == '(' || c == ')')
+                        .split(',')
+                        .map(|s| s.parse().unwrap())
+                        .collect()
+                })
+                .collect();
+            buttons.reverse();
+            let joltages: Vec<usize> = parts
+                .last()
+                .unwrap()
+                .trim_matches(|c| c == '{' || c == '}')
+                .split(',')
+                .map(|s| s.parse().unwrap())
+                .collect();
+            Machine {
+                lights,
+                buttons,
+                joltages,
+            }
+        })
+        .collect()
+}
+
+pub fn part_one(machines: &[Machine]) -> Option<u64> {
+    Some(machines.iter().map(Machine::fewest_light_presses).sum())
+}
+
+pub fn part_two(machines: &[Machine]) -> Option<u64> {
+    Some(machines.iter().map(Machine::fewest_joltage_presses).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use good_lp::{Expression, Solution, SolverModel, constraint, default_solver, variables};
+
+    /// The fewest joltage presses as found by an LP solver. Its floating-point objective is
+    /// rounded, so this only serves to cross-check the exact solver.
+    fn fewest_joltage_presses_lp(machine: &Machine) -> u64 {
+        let matrix = machine.joltage_matrix();
+        variables!(problem: 0 <= x[machine.buttons.len()] (integer));
+        let objective: Expression = x.iter().sum();
+        let mut solver = problem.minimise(objective).using(default_solver);
+        for (row, &joltage) in matrix.iter().zip(&machine.joltages) {
+            let expr: Expression = x
+                .iter()
+                .zip(row)
+                .map(|(&var, &coefficient)| var * coefficient as f64)
+                .sum();
+            solver.add_constraint(constraint!(expr == joltage as f64));
+        }
+        let solution = solver.solve().unwrap();
+
+        // Need to sum as floats then round to avoid losing .999999... values
+        let float_val: f64 = x.iter().map(|&var| solution.value(var)).sum();
+        float_val.round() as u64
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
+        assert_eq!(result, Some(7));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )));
+        assert_eq!(result, Some(33));
+    }
+
+    #[test]
+    fn test_part_two_matches_lp() {
+        let machines = parse(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        for machine in machines.iter() {
+            assert_eq!(
+                machine.fewest_joltage_presses(),
+                fewest_joltage_presses_lp(machine)
+            );
+        }
+    }
+}
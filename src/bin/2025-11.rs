@@ -0,0 +1,101 @@
+use advent_of_code::memo::Memo;
+use std::collections::HashMap;
+
+advent_of_code::solution!(11);
+
+type Connections = HashMap<String, Vec<String>>;
+
+fn parse(input: &str) -> Connections {
+    let mut connections: HashMap<String, Vec<String>> = HashMap::new();
+    for line in input.lines() {
+        let mut parts = line.split(":");
+        let key: String = parts.next().unwrap().to_string();
+        let value: Vec<String> = parts
+            .next()
+            .unwrap()
+            .split_whitespace()
+            .map(|s| s.to_string())
+            .collect();
+        connections.insert(key, value);
+    }
+    connections
+}
+
+fn count_connections_pt1<'a>(memo: &mut Memo<'a, Connections, &'a str, u64>, key: &'a str) -> u64 {
+    memo.get_or_insert_with(key, |memo, &key| {
+        let outputs: &Vec<String> = memo.input().get(key).unwrap();
+        if outputs.iter().next().unwrap() == "out" {
+            return 1;
+        }
+        outputs
+            .iter()
+            .map(|output| count_connections_pt1(memo, output))
+            .sum()
+    })
+}
+
+fn count_connections_pt2<'a>(
+    memo: &mut Memo<'a, Connections, (&'a str, bool, bool), u64>,
+    key: &'a str,
+    found_dac: bool,
+    found_fft: bool,
+) -> u64 {
+    memo.get_or_insert_with(
+        (key, found_dac, found_fft),
+        |memo, &(key, mut found_dac, mut found_fft)| {
+            let outputs: &Vec<String> = memo.input().get(key).unwrap();
+            if outputs.iter().next().unwrap() == "out" {
+                if found_dac && found_fft {
+                    return 1;
+                } else {
+                    return 0;
+                }
+            }
+            if key == "fft" {
+                found_fft = true;
+            } else if key == "dac" {
+                found_dac = true;
+            }
+            outputs
+                .iter()
+                .map(|output| count_connections_pt2(memo, output, found_dac, found_fft))
+                .sum()
+        },
+    )
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let connections = parse(input);
+    Some(count_connections_pt1(&mut Memo::new(&connections), "you"))
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let connections = parse(input);
+    Some(count_connections_pt2(
+        &mut Memo::new(&connections),
+        "svr",
+        false,
+        false,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 1,
+        ));
+        assert_eq!(result, Some(5));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file_part(
+            "examples", YEAR, DAY, 2,
+        ));
+        assert_eq!(result, Some(2));
+    }
+}
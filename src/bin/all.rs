@@ -0,0 +1,22 @@
+//! Runs the selected days in a single process instead of spawning one binary per day.
+//! Used by `cargo all` and `cargo time`, the day modules are collected by `build.rs`.
+
+// NOTE: every day module declares a dhat allocator when the feature is enabled, so they can not
+// be linked into a single binary. `cargo solve <day> --dhat` profiles one day at a time instead.
+#[cfg(not(any(test, feature = "dhat-heap")))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
+fn main() {
+    #[cfg(feature = "dhat-heap")]
+    {
+        eprintln!(
+            "the `all` binary does not support dhat, use `cargo solve <day> --dhat` instead."
+        );
+        std::process::exit(1);
+    }
+
+    #[cfg(not(any(test, feature = "dhat-heap")))]
+    advent_of_code::template::registry::run(solutions::SOLUTIONS);
+}
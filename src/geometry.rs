@@ -0,0 +1,391 @@
+//! Points and axis-aligned boxes in 2D and 3D with integer coordinates.
+//!
+//! All distances stay in the coordinate type, so they can be compared exactly: use
+//! [`Point3::distance_squared`] to order points by their euclidean distance without `f64::sqrt`.
+
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use std::str::FromStr;
+
+/// An integer type usable as coordinate.
+pub trait Coordinate:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ONE: Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+impl_coordinate!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// The distance between two values, which does not underflow for unsigned coordinates.
+fn abs_diff<T: Coordinate>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+/// Parse the comma-separated coordinates of a point, e.g. `3,-4`.
+fn parse_coordinates<T: FromStr, const N: usize>(s: &str) -> Result<[T; N], String> {
+    let values: Vec<T> = s
+        .split(',')
+        .map(|x| {
+            x.trim()
+                .parse()
+                .map_err(|_| format!("invalid coordinate `{}` in `{s}`.", x.trim()))
+        })
+        .collect::<Result<_, _>>()?;
+
+    values
+        .try_into()
+        .map_err(|_| format!("expected {N} coordinates, got `{s}`."))
+}
+
+/// Parse one point per line, e.g. `3,4` for a [`Point2`] or `3,4,5` for a [`Point3`].
+pub fn parse_lines<P: FromStr<Err = String>>(input: &str) -> Result<Vec<P>, String> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| line.parse().map_err(|e| format!("line {}: {e}", index + 1)))
+        .collect()
+}
+
+/// A point, or vector, in 2D.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+
+    /// The sum of the distances along both axes.
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The largest of the distances along both axes, i.e. the number of king moves between both points.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// The square of the euclidean distance.
+    pub fn distance_squared(self, other: Self) -> T {
+        let (dx, dy) = (abs_diff(self.x, other.x), abs_diff(self.y, other.y));
+        dx * dx + dy * dy
+    }
+
+    /// The smallest coordinates of both points on each axis.
+    pub fn min(self, other: Self) -> Self {
+        Point2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// The largest coordinates of both points on each axis.
+    pub fn max(self, other: Self) -> Self {
+        Point2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+}
+
+/// A point, or vector, in 3D.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// The sum of the distances along all axes.
+    pub fn manhattan(self, other: Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    /// The largest of the distances along all axes.
+    pub fn chebyshev(self, other: Self) -> T {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
+    }
+
+    /// The square of the euclidean distance.
+    pub fn distance_squared(self, other: Self) -> T {
+        let dx = abs_diff(self.x, other.x);
+        let dy = abs_diff(self.y, other.y);
+        let dz = abs_diff(self.z, other.z);
+        dx * dx + dy * dy + dz * dz
+    }
+
+    /// The smallest coordinates of both points on each axis.
+    pub fn min(self, other: Self) -> Self {
+        Point3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// The largest coordinates of both points on each axis.
+    pub fn max(self, other: Self) -> Self {
+        Point3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+}
+
+macro_rules! impl_vector_ops {
+    ($point:ident { $($axis:ident),* }) => {
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($axis: self.$axis + other.$axis),* }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($axis: self.$axis - other.$axis),* }
+            }
+        }
+
+        /// Scales the vector.
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($axis: self.$axis * factor),* }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        /// Parses the comma-separated coordinates the puzzles list points as.
+        impl<T: FromStr> FromStr for $point<T> {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let [$($axis),*] = parse_coordinates(s)?;
+                Ok($point { $($axis),* })
+            }
+        }
+
+        /// Writes the coordinates comma-separated, as they are parsed.
+        impl<T: Display> Display for $point<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let coordinates = [$(self.$axis.to_string()),*];
+                f.write_str(&coordinates.join(","))
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Point2 { x, y });
+impl_vector_ops!(Point3 { x, y, z });
+
+/// An axis-aligned rectangle of integer cells. Both corners are part of the box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Box2<T> {
+    pub min: Point2<T>,
+    pub max: Point2<T>,
+}
+
+impl<T: Coordinate> Box2<T> {
+    /// The smallest box that contains both corners, which may be given in any order.
+    pub fn from_corners(a: Point2<T>, b: Point2<T>) -> Self {
+        Box2 {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    /// The number of cells in the box.
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: Point2<T>) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+    }
+
+    /// Whether the other box lies fully within this box.
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// The cells both boxes share, or `None` if they are disjoint.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        (min.x <= max.x && min.y <= max.y).then_some(Box2 { min, max })
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+/// An axis-aligned cuboid of integer cells. Both corners are part of the box.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Box3<T> {
+    pub min: Point3<T>,
+    pub max: Point3<T>,
+}
+
+impl<T: Coordinate> Box3<T> {
+    /// The smallest box that contains both corners, which may be given in any order.
+    pub fn from_corners(a: Point3<T>, b: Point3<T>) -> Self {
+        Box3 {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// The number of cells in the box.
+    pub fn volume(&self) -> T {
+        let size = self.max - self.min + Point3::new(T::ONE, T::ONE, T::ONE);
+        size.x * size.y * size.z
+    }
+
+    pub fn contains(&self, point: Point3<T>) -> bool {
+        self.min.min(point) == self.min && self.max.max(point) == self.max
+    }
+
+    /// Whether the other box lies fully within this box.
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// The cells both boxes share, or `None` if they are disjoint.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        (min.x <= max.x && min.y <= max.y && min.z <= max.z).then_some(Box3 { min, max })
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Box2, Box3, Point2, Point3, parse_lines};
+
+    #[test]
+    fn computes_vectors() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(a * 3, Point2::new(3, 6));
+
+        let mut c = Point3::new(1, 1, 1);
+        c += Point3::new(1, 2, 3);
+        c -= Point3::new(0, 0, 1);
+        assert_eq!(c, Point3::new(2, 3, 3));
+    }
+
+    #[test]
+    fn computes_distances() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.distance_squared(b), 25);
+
+        // unsigned coordinates do not underflow.
+        let a = Point3::<u64>::new(5, 0, 2);
+        let b = Point3::<u64>::new(1, 3, 2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.chebyshev(a), 4);
+        assert_eq!(a.distance_squared(b), 25);
+    }
+
+    #[test]
+    fn parses_and_displays_points() {
+        assert_eq!("3, -4".parse(), Ok(Point2::new(3, -4)));
+        assert_eq!("162,817,812".parse(), Ok(Point3::new(162, 817, 812)));
+        assert!("1,2,3".parse::<Point2<i32>>().is_err());
+        assert!("1,a".parse::<Point2<i32>>().is_err());
+        assert_eq!(Point3::new(1, -2, 3).to_string(), "1,-2,3");
+
+        let points: Vec<Point2<u64>> = parse_lines("7,1\n11,1\n").unwrap();
+        assert_eq!(points, vec![Point2::new(7, 1), Point2::new(11, 1)]);
+        assert!(parse_lines::<Point2<u64>>("7,1\n11").is_err());
+    }
+
+    #[test]
+    fn computes_boxes() {
+        let a = Box2::from_corners(Point2::new(9, 5), Point2::new(2, 3));
+        assert_eq!(a.min, Point2::new(2, 3));
+        assert_eq!((a.width(), a.height(), a.area()), (8, 3, 24));
+        assert!(a.contains(Point2::new(9, 3)));
+        assert!(!a.contains(Point2::new(10, 3)));
+
+        let b = Box2::from_corners(Point2::new(9, 5), Point2::new(12, 7));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Box2::from_corners(Point2::new(9, 5), Point2::new(9, 5)))
+        );
+        assert!(!a.intersects(&Box2::from_corners(Point2::new(10, 0), Point2::new(12, 2))));
+        assert!(a.contains_box(&Box2::from_corners(Point2::new(3, 4), Point2::new(4, 5))));
+        assert!(!a.contains_box(&b));
+    }
+
+    #[test]
+    fn computes_cuboids() {
+        let a = Box3::from_corners(Point3::new(0, 0, 0), Point3::new(2, 2, 2));
+        let b = Box3::from_corners(Point3::new(1, 1, 1), Point3::new(3, 3, 3));
+        assert_eq!(a.volume(), 27);
+        assert_eq!(a.intersection(&b).map(|x| x.volume()), Some(8));
+        assert!(a.contains(Point3::new(2, 0, 1)));
+        assert!(!a.contains(Point3::new(2, 0, 3)));
+        assert!(!a.contains_box(&b));
+        assert!(!a.intersects(&Box3::from_corners(
+            Point3::new(3, 0, 0),
+            Point3::new(4, 1, 1)
+        )));
+    }
+}
@@ -0,0 +1,354 @@
+//! Linear algebra over GF(2), the field of the bits `0` and `1` where addition is XOR.
+//!
+//! Puzzles about toggling lights with buttons, where pressing a button twice undoes it, are
+//! linear systems over GF(2): each row is a light, each column a button.
+
+use std::fmt::{self, Display};
+
+/// A vector of bits, packed into 64-bit words.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    /// A vector of `len` zero bits.
+    pub fn zeros(len: usize) -> Self {
+        BitVec {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "bit {index} out of bounds");
+        self.words[index / 64] >> (index % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "bit {index} out of bounds");
+        let mask = 1 << (index % 64);
+        if value {
+            self.words[index / 64] |= mask;
+        } else {
+            self.words[index / 64] &= !mask;
+        }
+    }
+
+    /// Add the other vector, i.e. XOR it into this one.
+    pub fn add_assign(&mut self, other: &BitVec) {
+        assert_eq!(self.len, other.len, "vectors differ in length");
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a ^= b;
+        }
+    }
+
+    /// The number of one bits.
+    pub fn weight(&self) -> usize {
+        self.words.iter().map(|x| x.count_ones() as usize).sum()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&x| x == 0)
+    }
+
+    /// The indices of the one bits, ascending.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len).filter(|&x| self.get(x))
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let bits: Vec<bool> = iter.into_iter().collect();
+        let mut vec = BitVec::zeros(bits.len());
+        for (index, bit) in bits.into_iter().enumerate() {
+            vec.set(index, bit);
+        }
+        vec
+    }
+}
+
+/// Writes the bits as `0` and `1`, lowest index first.
+impl Display for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for index in 0..self.len {
+            f.write_str(if self.get(index) { "1" } else { "0" })?;
+        }
+        Ok(())
+    }
+}
+
+/// A matrix over GF(2), stored as one [`BitVec`] per row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Matrix {
+    rows: Vec<BitVec>,
+    cols: usize,
+}
+
+impl Matrix {
+    /// A matrix of zeros.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows: vec![BitVec::zeros(cols); rows],
+            cols,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        self.rows[row].get(col)
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) {
+        self.rows[row].set(col, value);
+    }
+
+    pub fn row(&self, row: usize) -> &BitVec {
+        &self.rows[row]
+    }
+
+    /// Bring the matrix into reduced row echelon form and return the pivot column of every
+    /// non-zero row, in row order.
+    pub fn row_reduce(&mut self) -> Vec<usize> {
+        self.row_reduce_columns(self.cols)
+    }
+
+    /// The number of linearly independent rows.
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce().len()
+    }
+
+    /// A basis of the vectors `x` with `Ax = 0`, one vector per free column.
+    pub fn null_space(&self) -> Vec<BitVec> {
+        let mut reduced = self.clone();
+        let pivots = reduced.row_reduce();
+        reduced.null_space_of_reduced(&pivots)
+    }
+
+    /// Any solution of `Ax = b`, with all free variables set to zero.
+    /// Returns `None` if the system has no solution.
+    pub fn solve(&self, b: &BitVec) -> Option<BitVec> {
+        self.reduce_augmented(b).map(|(solution, _, _)| solution)
+    }
+
+    /// A solution of `Ax = b` with the fewest one bits, or `None` if the system has no solution.
+    ///
+    /// Every solution is a particular solution plus a combination of the null space basis. The
+    /// combinations are enumerated in Gray code order, so that each step adds a single basis vector.
+    /// Panics if the null space has 64 or more dimensions, which is too many to enumerate.
+    pub fn min_weight_solution(&self, b: &BitVec) -> Option<BitVec> {
+        let (mut current, reduced, pivots) = self.reduce_augmented(b)?;
+        let basis = reduced.null_space_of_reduced(&pivots);
+        assert!(
+            basis.len() < 64,
+            "null space of {} dimensions is too large to enumerate",
+            basis.len()
+        );
+
+        let mut best = current.clone();
+        for step in 1..1u64 << basis.len() {
+            current.add_assign(&basis[step.trailing_zeros() as usize]);
+            if current.weight() < best.weight() {
+                best.clone_from(&current);
+            }
+        }
+
+        Some(best)
+    }
+
+    /// Row reduce, only choosing pivots in the columns before `limit`.
+    fn row_reduce_columns(&mut self, limit: usize) -> Vec<usize> {
+        let mut pivots = Vec::new();
+
+        for col in 0..limit {
+            let row = pivots.len();
+            let Some(pivot) = (row..self.rows.len()).find(|&x| self.rows[x].get(col)) else {
+                continue;
+            };
+            self.rows.swap(row, pivot);
+
+            let pivot_row = self.rows[row].clone();
+            for (index, other) in self.rows.iter_mut().enumerate() {
+                if index != row && other.get(col) {
+                    other.add_assign(&pivot_row);
+                }
+            }
+
+            pivots.push(col);
+        }
+
+        pivots
+    }
+
+    fn null_space_of_reduced(&self, pivots: &[usize]) -> Vec<BitVec> {
+        (0..self.cols)
+            .filter(|col| !pivots.contains(col))
+            .map(|free| {
+                let mut vector = BitVec::zeros(self.cols);
+                vector.set(free, true);
+                for (row, &pivot) in pivots.iter().enumerate() {
+                    vector.set(pivot, self.rows[row].get(free));
+                }
+                vector
+            })
+            .collect()
+    }
+
+    /// Row reduce `[A | b]`. Returns the particular solution, the reduced `A` and its pivot columns.
+    fn reduce_augmented(&self, b: &BitVec) -> Option<(BitVec, Matrix, Vec<usize>)> {
+        assert_eq!(b.len(), self.rows(), "right-hand side differs in length");
+
+        let mut augmented = Matrix {
+            rows: self
+                .rows
+                .iter()
+                .enumerate()
+                .map(|(index, row)| {
+                    (0..self.cols)
+                        .map(|x| row.get(x))
+                        .chain([b.get(index)])
+                        .collect()
+                })
+                .collect(),
+            cols: self.cols + 1,
+        };
+        let pivots = augmented.row_reduce_columns(self.cols);
+
+        // a remaining row of `0 = 1` makes the system inconsistent.
+        if augmented.rows[pivots.len()..]
+            .iter()
+            .any(|row| row.get(self.cols))
+        {
+            return None;
+        }
+
+        let mut solution = BitVec::zeros(self.cols);
+        for (row, &pivot) in pivots.iter().enumerate() {
+            solution.set(pivot, augmented.rows[row].get(self.cols));
+        }
+
+        let reduced = Matrix {
+            rows: augmented
+                .rows
+                .iter()
+                .map(|row| (0..self.cols).map(|x| row.get(x)).collect())
+                .collect(),
+            cols: self.cols,
+        };
+
+        Some((solution, reduced, pivots))
+    }
+}
+
+impl FromIterator<BitVec> for Matrix {
+    /// Collect rows of equal length into a matrix.
+    fn from_iter<I: IntoIterator<Item = BitVec>>(iter: I) -> Self {
+        let rows: Vec<BitVec> = iter.into_iter().collect();
+        let cols = rows.first().map_or(0, BitVec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows differ in length"
+        );
+        Matrix { rows, cols }
+    }
+}
+
+/// Writes one row per line.
+impl Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{BitVec, Matrix};
+
+    fn bits(s: &str) -> BitVec {
+        s.chars().map(|c| c == '1').collect()
+    }
+
+    fn matrix(rows: &[&str]) -> Matrix {
+        rows.iter().map(|row| bits(row)).collect()
+    }
+
+    /// `Ax` over GF(2).
+    fn multiply(a: &Matrix, x: &BitVec) -> BitVec {
+        (0..a.rows())
+            .map(|row| a.row(row).ones().filter(|&col| x.get(col)).count() % 2 == 1)
+            .collect()
+    }
+
+    #[test]
+    fn packs_bits() {
+        let mut vec = BitVec::zeros(130);
+        vec.set(0, true);
+        vec.set(64, true);
+        vec.set(129, true);
+        assert_eq!(vec.weight(), 3);
+        assert_eq!(vec.ones().collect::<Vec<_>>(), vec![0, 64, 129]);
+
+        vec.add_assign(&vec.clone());
+        assert!(vec.is_zero());
+        assert_eq!(bits("0110").to_string(), "0110");
+    }
+
+    #[test]
+    fn reduces_rows() {
+        let mut a = matrix(&["110", "011", "101"]);
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.row_reduce(), vec![0, 1]);
+        assert_eq!(a.to_string(), "101\n011\n000");
+        assert_eq!(a.null_space(), vec![bits("111")]);
+    }
+
+    #[test]
+    fn solves_systems() {
+        let a = matrix(&["110", "011", "101"]);
+        let b = bits("101");
+        let x = a.solve(&b).unwrap();
+        assert_eq!(multiply(&a, &x), b);
+        assert_eq!(a.solve(&bits("100")), None);
+        assert_eq!(a.min_weight_solution(&bits("100")), None);
+    }
+
+    #[test]
+    fn finds_minimum_weight_solutions() {
+        // lights `.##.` of the first example machine of 2025 day 10, one column per button.
+        let a = matrix(&["000011", "010001", "001110", "110100"]);
+        let b = bits("0110");
+
+        let x = a.min_weight_solution(&b).unwrap();
+        assert_eq!(multiply(&a, &x), b);
+        assert_eq!(x.weight(), 2);
+
+        for vector in a.null_space() {
+            assert!(multiply(&a, &vector).is_zero());
+        }
+    }
+}
@@ -0,0 +1,290 @@
+//! Rectangular 2D grids, as most puzzles draw their input.
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// Offsets of the neighbors sharing an edge with a cell, as `(row, col)`: up, right, down, left.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of all neighbors of a cell, including the diagonal ones, in reading order.
+const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row. Positions are `(row, col)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from its rows. Fails if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let cols = rows.first().map_or(0, Vec::len);
+
+        if let Some(index) = rows.iter().position(|row| row.len() != cols) {
+            return Err(format!(
+                "row {} has {} cells, expected {cols}.",
+                index + 1,
+                rows[index].len()
+            ));
+        }
+
+        Ok(Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parse a grid with one line per row and one character per cell.
+    /// Fails if the lines differ in length or a character is rejected by `parse_cell`.
+    pub fn parse(input: &str, parse_cell: impl Fn(char) -> Option<T>) -> Result<Self, String> {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| {
+                        parse_cell(c).ok_or(format!(
+                            "unexpected `{c}` at row {}, column {}.",
+                            row + 1,
+                            col + 1
+                        ))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Self::from_rows(rows)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Whether the position lies within the grid. Accepts positions off the grid on any side.
+    pub fn contains(&self, row: isize, col: isize) -> bool {
+        self.checked_position(row, col).is_some()
+    }
+
+    /// The cell at a position, or `None` if it lies off the grid.
+    pub fn get(&self, row: isize, col: isize) -> Option<&T> {
+        let (row, col) = self.checked_position(row, col)?;
+        Some(&self[(row, col)])
+    }
+
+    /// The cell at a position, or `None` if it lies off the grid.
+    pub fn get_mut(&mut self, row: isize, col: isize) -> Option<&mut T> {
+        let (row, col) = self.checked_position(row, col)?;
+        Some(&mut self[(row, col)])
+    }
+
+    /// All positions of the grid, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// All cells of the grid with their positions, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The positions of the up to 4 neighbors that share an edge with the cell.
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(row, col, &ORTHOGONAL)
+    }
+
+    /// The positions of the up to 8 neighbors of the cell, including the diagonal ones.
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(row, col, &ADJACENT)
+    }
+
+    /// The cells of a row, left to right.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// The cells of a column, top to bottom.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {col} out of bounds");
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    /// The position of the first cell with the value, in reading order.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, x)| *x == value).map(|(pos, _)| pos)
+    }
+
+    /// A grid of the same size with every cell converted.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: (0..self.cols)
+                .flat_map(|col| self.column(col).cloned())
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    fn checked_position(&self, row: isize, col: isize) -> Option<(usize, usize)> {
+        let row = usize::try_from(row).ok().filter(|&x| x < self.rows)?;
+        let col = usize::try_from(col).ok().filter(|&x| x < self.cols)?;
+        Some((row, col))
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        row: usize,
+        col: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(d_row, d_col)| {
+            let row = row.checked_add_signed(d_row)?;
+            let col = col.checked_add_signed(d_col)?;
+            (row < self.rows && col < self.cols).then_some((row, col))
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "position ({row}, {col}) out of bounds"
+        );
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "position ({row}, {col}) out of bounds"
+        );
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, Some)
+    }
+}
+
+/// Writes one line per row, without a trailing newline, so that a parsed grid prints like its input.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays_grids() {
+        let input = "#..#\n.##.\n#..#\n";
+        let grid: Grid<char> = input.parse().unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (3, 4));
+        assert_eq!(grid.to_string(), input.trim_end());
+
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits[(1, 0)], 3);
+        assert_eq!(digits.map(|x| x * 2).to_string(), "24\n68");
+    }
+
+    #[test]
+    fn rejects_malformed_grids() {
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        assert!(Grid::parse("1a", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn checks_signed_positions() {
+        let mut grid = grid();
+        assert_eq!(grid.get(1, 2), Some(&'f'));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert!(grid.contains(0, 0));
+        assert!(!grid.contains(2, 0));
+
+        *grid.get_mut(0, 0).unwrap() = 'x';
+        assert_eq!(grid[(0, 0)], 'x');
+    }
+
+    #[test]
+    fn iterates_neighbors() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbors8(1, 1).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn views_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        assert_eq!(grid.positions().count(), 6);
+    }
+}
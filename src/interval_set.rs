@@ -0,0 +1,289 @@
+//! Sets of integers, stored as sorted, disjoint inclusive ranges.
+
+use std::fmt::{self, Display};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::geometry::Coordinate;
+
+/// A set of integers stored as sorted, disjoint inclusive ranges. Overlapping and adjacent ranges
+/// are merged on insertion, e.g. `1-3` and `4-6` are kept as `1-6`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+/// Whether a range ending at `end` and a later range starting at `start` overlap or are adjacent.
+fn touches<T: Coordinate>(end: T, start: T) -> bool {
+    // `end + 1` can not overflow, as `end < start`. `start - end` would for distant signed bounds.
+    start <= end || end + T::ONE == start
+}
+
+impl<T: Coordinate> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a range, merging it with the ranges it overlaps or is adjacent to. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // the ranges before `first` end before the new range starts, the ones from `last` on start after it.
+        let first = self.ranges.partition_point(|&(_, x)| !touches(x, start));
+        let last = self.ranges.partition_point(|&(x, _)| touches(end, x));
+
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// Whether the value lies in any of the ranges.
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|&(start, _)| start <= value);
+        index > 0 && value <= self.ranges[index - 1].1
+    }
+
+    /// The number of integers in the set.
+    ///
+    /// The count is a `T` as well, so it overflows if the set covers every value of `T`, e.g. `0-255`
+    /// for `u8`. Use a wider `T` for sets that may span the whole domain.
+    pub fn covered_len(&self) -> T
+    where
+        T: std::iter::Sum,
+    {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| end - start + T::ONE)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The sorted, disjoint ranges of the set.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The integers that are in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    /// The integers that are in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.ranges.get(i), other.ranges.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// The integers of this set that are not in the other set.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for &(start, end) in &self.ranges {
+            let mut start = start;
+
+            // skip the ranges of the other set that end before this range.
+            while other.ranges.get(j).is_some_and(|&(_, x)| x < start) {
+                j += 1;
+            }
+
+            let mut k = j;
+            let mut remaining = true;
+            while let Some(&(cut_start, cut_end)) = other.ranges.get(k).filter(|&&(x, _)| x <= end)
+            {
+                if cut_start > start {
+                    ranges.push((start, cut_start - T::ONE));
+                }
+                if cut_end >= end {
+                    remaining = false;
+                    break;
+                }
+                start = cut_end + T::ONE;
+                k += 1;
+            }
+
+            if remaining {
+                ranges.push((start, end));
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Coordinate> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// Parses ranges like `3-5`, separated by commas or newlines. Empty entries are skipped.
+impl<T: Coordinate + FromStr> FromStr for IntervalSet<T> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split([',', '\n'])
+            .map(str::trim)
+            .filter(|x| !x.is_empty())
+            .map(|entry| {
+                // the first character may be the sign of a negative start.
+                let separator = entry
+                    .char_indices()
+                    .skip(1)
+                    .find(|&(_, c)| c == '-')
+                    .map(|(index, _)| index)
+                    .ok_or(format!("expected `start-end`, got `{entry}`."))?;
+
+                let parse = |x: &str| {
+                    x.trim()
+                        .parse::<T>()
+                        .map_err(|_| format!("invalid bound `{}` in `{entry}`.", x.trim()))
+                };
+
+                Ok(parse(&entry[..separator])?..=parse(&entry[separator + 1..])?)
+            })
+            .collect()
+    }
+}
+
+/// Writes the ranges comma-separated, as they are parsed.
+impl<T: Display> Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (start, end)) in self.ranges.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{start}-{end}")?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::IntervalSet;
+
+    fn set(s: &str) -> IntervalSet<i64> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn merges_inserted_ranges() {
+        let mut ranges = set("10-20");
+        ranges.insert(30..=40);
+        ranges.insert(0..=2);
+        assert_eq!(ranges.to_string(), "0-2,10-20,30-40");
+
+        ranges.insert(21..=29);
+        assert_eq!(ranges.to_string(), "0-2,10-40");
+
+        ranges.insert(3..=3);
+        ranges.insert(5..=4);
+        assert_eq!(ranges.to_string(), "0-3,10-40");
+
+        ranges.insert(-5..=50);
+        assert_eq!(ranges.to_string(), "-5-50");
+        assert_eq!(ranges.covered_len(), 56);
+    }
+
+    #[test]
+    fn does_not_overflow_at_bounds() {
+        let mut ranges: IntervalSet<u8> = "250-255,0-3".parse().unwrap();
+        ranges.insert(4..=10);
+        assert_eq!(ranges.to_string(), "0-10,250-255");
+        assert!(ranges.contains(255));
+        assert_eq!(
+            ranges.difference(&"0-255".parse().unwrap()),
+            IntervalSet::new()
+        );
+    }
+
+    #[test]
+    fn does_not_overflow_between_distant_bounds() {
+        let mut ranges = IntervalSet::new();
+        ranges.insert(i64::MIN..=-1);
+        ranges.insert(i64::MAX..=i64::MAX);
+        assert_eq!(ranges.iter().count(), 2);
+        assert!(!ranges.contains(0));
+        assert!(ranges.contains(i64::MAX));
+
+        ranges.insert(0..=i64::MAX - 1);
+        assert_eq!(ranges.iter().collect::<Vec<_>>(), vec![i64::MIN..=i64::MAX]);
+    }
+
+    #[test]
+    fn queries_points() {
+        let ranges = set("3-5\n10-14\n16-20\n12-18");
+        assert_eq!(ranges.to_string(), "3-5,10-20");
+        assert!(!ranges.contains(1));
+        assert!(ranges.contains(5));
+        assert!(!ranges.contains(8));
+        assert!(ranges.contains(11));
+        assert!(ranges.contains(20));
+        assert!(!ranges.contains(32));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set("0-10,20-30");
+        let b = set("5-25,40-50");
+        assert_eq!(a.union(&b).to_string(), "0-30,40-50");
+        assert_eq!(a.intersection(&b).to_string(), "5-10,20-25");
+        assert_eq!(a.difference(&b).to_string(), "0-4,26-30");
+        assert_eq!(b.difference(&a).to_string(), "11-19,40-50");
+        assert_eq!(
+            a.difference(&set("2-3,5-6")).to_string(),
+            "0-1,4-4,7-10,20-30"
+        );
+        assert!(a.intersection(&set("11-19")).is_empty());
+    }
+
+    #[test]
+    fn parses_negative_bounds() {
+        assert_eq!(set("-10--5,-2-3").to_string(), "-10--5,-2-3");
+        assert!("1".parse::<IntervalSet<i64>>().is_err());
+        assert!("1-a".parse::<IntervalSet<i64>>().is_err());
+    }
+}
@@ -1,3 +1,11 @@
+pub mod geometry;
+pub mod gf2;
+pub mod grid;
+pub mod interval_set;
+pub mod linear;
+pub mod memo;
+pub mod ocr;
 pub mod template;
+pub mod union_find;
 
 // Use this file to add helper functions and additional modules.
@@ -0,0 +1,358 @@
+//! Exact solutions of small linear systems `Ax = b` in non-negative integers.
+//!
+//! The systems are eliminated with integer row operations, which keeps every coefficient exact
+//! where floating-point LP solvers would need their results rounded.
+
+/// The non-negative integer solution of `Ax = b` with the smallest sum of `x`, or `None` if there
+/// is no such solution. `a` holds one row of coefficients per equation.
+///
+/// The system is brought into reduced row echelon form, after which the free variables are
+/// searched depth-first. As all coefficients are non-negative, no variable can exceed what the
+/// variables assigned before it leave of any equation it appears in, and branches that cannot beat
+/// the best sum found so far are cut. This is meant for systems with few free variables and small
+/// right-hand sides.
+pub fn min_sum_solution(a: &[Vec<u64>], b: &[u64]) -> Option<Vec<u64>> {
+    assert_eq!(a.len(), b.len(), "right-hand side differs in length");
+    let cols = a.first().map_or(0, Vec::len);
+    assert!(
+        a.iter().all(|row| row.len() == cols),
+        "rows differ in length"
+    );
+
+    let (rows, pivots) = row_reduce(a, b);
+
+    // a remaining row of `0 = c` with `c != 0` makes the system inconsistent.
+    if rows[pivots.len()..].iter().any(|row| row[cols] != 0) {
+        return None;
+    }
+
+    let rows = &rows[..pivots.len()];
+    let free: Vec<usize> = (0..cols).filter(|col| !pivots.contains(col)).collect();
+
+    // every row is checked as soon as the last free variable it depends on is assigned, rows
+    // without free variables have a fixed solution and are checked upfront.
+    let mut checks = vec![Vec::new(); free.len()];
+    let mut fixed = Vec::new();
+    for (row, coefficients) in rows.iter().enumerate() {
+        match free.iter().rposition(|&col| coefficients[col] != 0) {
+            Some(depth) => checks[depth].push(row),
+            None => fixed.push(row),
+        }
+    }
+
+    // with every row reading `d * x_p + sum(c_f * x_f) = e`, the sum of all variables is
+    // `sum(e / d) + sum((1 - sum(c_f / d)) * x_f)`, a linear function of the free variables.
+    // it is multiplied by the least common multiple of the pivots to keep it in integers.
+    let scale = rows
+        .iter()
+        .zip(&pivots)
+        .fold(1, |acc, (row, &pivot)| lcm(acc, row[pivot]));
+    let share = |col: usize| -> i64 {
+        rows.iter()
+            .zip(&pivots)
+            .map(|(row, &pivot)| row[col] * (scale / row[pivot]))
+            .sum()
+    };
+
+    let mut search = Search {
+        rows,
+        pivots: &pivots,
+        free: &free,
+        weights: free.iter().map(|&col| scale - share(col)).collect(),
+        scale,
+        equations: a,
+        remaining: b.to_vec(),
+        checks: &checks,
+        values: vec![0; cols],
+        best: None,
+    };
+    if fixed.iter().any(|&row| search.pivot_value(row).is_none()) {
+        return None;
+    }
+
+    search.assign(0, share(cols));
+    search.best.map(|(_, values)| values)
+}
+
+/// Bring the augmented matrix `[A | b]` into reduced row echelon form, keeping all entries
+/// integers with a positive pivot. Returns the rows and the pivot column of every non-zero row.
+fn row_reduce(a: &[Vec<u64>], b: &[u64]) -> (Vec<Vec<i64>>, Vec<usize>) {
+    let cols = a.first().map_or(0, Vec::len);
+    let mut rows: Vec<Vec<i64>> = a
+        .iter()
+        .zip(b)
+        .map(|(row, &rhs)| row.iter().chain([&rhs]).map(|&x| x as i64).collect())
+        .collect();
+    let mut pivots = Vec::new();
+
+    for col in 0..cols {
+        let row = pivots.len();
+        let Some(pivot) = (row..rows.len()).find(|&x| rows[x][col] != 0) else {
+            continue;
+        };
+        rows.swap(row, pivot);
+        if rows[row][col] < 0 {
+            rows[row].iter_mut().for_each(|x| *x = -*x);
+        }
+
+        let pivot_row = rows[row].clone();
+        for (index, other) in rows.iter_mut().enumerate() {
+            if index != row && other[col] != 0 {
+                let factor = other[col];
+                for (x, &y) in other.iter_mut().zip(&pivot_row) {
+                    *x = *x * pivot_row[col] - y * factor;
+                }
+                reduce_by_gcd(other);
+            }
+        }
+
+        pivots.push(col);
+    }
+
+    (rows, pivots)
+}
+
+/// Divide the row by the greatest common divisor of its entries, keeping the numbers small.
+fn reduce_by_gcd(row: &mut [i64]) {
+    let divisor = row.iter().fold(0, |acc, &x| gcd(acc, x.unsigned_abs()));
+    if divisor > 1 {
+        row.iter_mut().for_each(|x| *x /= divisor as i64);
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn lcm(a: i64, b: i64) -> i64 {
+    a / gcd(a.unsigned_abs(), b.unsigned_abs()) as i64 * b
+}
+
+/// The state of the search over the free variables of a reduced system.
+struct Search<'a> {
+    rows: &'a [Vec<i64>],
+    pivots: &'a [usize],
+    free: &'a [usize],
+    /// How much a unit of each free variable changes the scaled sum of all variables.
+    weights: Vec<i64>,
+    /// The factor the sum of all variables is scaled by to keep the weights integers.
+    scale: i64,
+    /// The original equations, which bound the free variables.
+    equations: &'a [Vec<u64>],
+    /// The right-hand sides of the original equations, less the assigned free variables.
+    remaining: Vec<u64>,
+    /// The rows to check after assigning the free variable at each depth.
+    checks: &'a [Vec<usize>],
+    values: Vec<u64>,
+    best: Option<(u64, Vec<u64>)>,
+}
+
+impl Search<'_> {
+    /// The value of the pivot variable of the row, given the values of the free variables.
+    /// Returns `None` if it is negative or not an integer.
+    fn pivot_value(&self, row: usize) -> Option<u64> {
+        let coefficients = &self.rows[row];
+        let rhs = coefficients[self.values.len()]
+            - self
+                .free
+                .iter()
+                .map(|&col| coefficients[col] * self.values[col] as i64)
+                .sum::<i64>();
+        let pivot = coefficients[self.pivots[row]];
+
+        (rhs >= 0 && rhs % pivot == 0).then_some((rhs / pivot) as u64)
+    }
+
+    /// The largest value of the variable that leaves no remaining right-hand side negative.
+    fn bound(&self, col: usize) -> u64 {
+        self.equations
+            .iter()
+            .zip(&self.remaining)
+            .filter(|(row, _)| row[col] > 0)
+            .map(|(row, &rest)| rest / row[col])
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Take `value` times the variable out of the remaining right-hand sides, or put it back.
+    fn use_up(&mut self, col: usize, value: u64, undo: bool) {
+        for (row, rest) in self.equations.iter().zip(&mut self.remaining) {
+            if undo {
+                *rest += row[col] * value;
+            } else {
+                *rest -= row[col] * value;
+            }
+        }
+    }
+
+    /// A lower bound on the sum of the unassigned variables: every remaining right-hand side has to
+    /// be covered, and a unit of a variable covers at most the largest coefficient of the row.
+    fn remaining_lower_bound(&self) -> u64 {
+        self.equations
+            .iter()
+            .zip(&self.remaining)
+            .filter_map(|(row, &rest)| {
+                let largest = row.iter().copied().max().filter(|&x| x > 0)?;
+                Some(rest.div_ceil(largest))
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// A lower bound on the scaled contribution of the free variables from `depth` on, reached
+    /// by setting the ones of negative weight to their bounds.
+    fn unassigned_lower_bound(&self, depth: usize) -> i64 {
+        self.free[depth..]
+            .iter()
+            .zip(&self.weights[depth..])
+            .filter(|&(_, &weight)| weight < 0)
+            .map(|(&col, &weight)| weight * self.bound(col) as i64)
+            .sum()
+    }
+
+    /// Try all values of the free variable at `depth` and the ones after it, where `objective` is
+    /// the scaled sum of all variables with the unassigned free variables at zero.
+    fn assign(&mut self, depth: usize, objective: i64) {
+        if depth == self.free.len() {
+            let mut values = self.values.clone();
+            for (row, &pivot) in self.pivots.iter().enumerate() {
+                let Some(value) = self.pivot_value(row) else {
+                    return;
+                };
+                values[pivot] = value;
+            }
+
+            let total = values.iter().sum();
+            if self.best.as_ref().is_none_or(|&(best, _)| total < best) {
+                self.best = Some((total, values));
+            }
+            return;
+        }
+
+        let (col, weight) = (self.free[depth], self.weights[depth]);
+        for value in 0..=self.bound(col) {
+            self.values[col] = value;
+            if !self.checks[depth]
+                .iter()
+                .all(|&row| self.pivot_value(row).is_some())
+            {
+                continue;
+            }
+
+            let objective = objective + weight * value as i64;
+            self.use_up(col, value, false);
+            let lower = objective + self.unassigned_lower_bound(depth + 1);
+            let covered = self.free[..=depth]
+                .iter()
+                .map(|&col| self.values[col])
+                .sum::<u64>()
+                + self.remaining_lower_bound();
+            let (bounded, uncovered) = match self.best {
+                Some((best, _)) => (lower >= best as i64 * self.scale, covered >= best),
+                None => (false, false),
+            };
+            if !bounded && !uncovered {
+                self.assign(depth + 1, objective);
+            }
+            self.use_up(col, value, true);
+
+            // larger values only raise the bound of a variable with non-negative weight.
+            if bounded && weight >= 0 {
+                break;
+            }
+        }
+        self.values[col] = 0;
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::min_sum_solution;
+
+    fn multiply(a: &[Vec<u64>], x: &[u64]) -> Vec<u64> {
+        a.iter()
+            .map(|row| row.iter().zip(x).map(|(a, x)| a * x).sum())
+            .collect()
+    }
+
+    /// The smallest sum of all solutions with entries up to `limit`, by trying every one of them.
+    fn brute_force(a: &[Vec<u64>], b: &[u64], limit: u64) -> Option<u64> {
+        let cols = a[0].len();
+        let mut x = vec![0; cols];
+        let mut best = None;
+        loop {
+            if multiply(a, &x) == b {
+                let sum: u64 = x.iter().sum();
+                best = Some(best.map_or(sum, |best: u64| best.min(sum)));
+            }
+
+            let Some(index) = x.iter().position(|&value| value < limit) else {
+                return best;
+            };
+            x[..index].fill(0);
+            x[index] += 1;
+        }
+    }
+
+    #[test]
+    fn solves_example_machine() {
+        // the buttons and joltages of the first example machine of 2025 day 10.
+        let a = vec![
+            vec![0, 0, 0, 0, 1, 1],
+            vec![0, 1, 0, 0, 0, 1],
+            vec![0, 0, 1, 1, 1, 0],
+            vec![1, 1, 0, 1, 0, 0],
+        ];
+        let b = [3, 5, 4, 7];
+
+        let x = min_sum_solution(&a, &b).unwrap();
+        assert_eq!(multiply(&a, &x), b);
+        assert_eq!(x.iter().sum::<u64>(), 10);
+    }
+
+    #[test]
+    fn solves_systems_with_free_variables() {
+        // x + y = 3, y + z = 5
+        let a = [vec![1, 1, 0], vec![0, 1, 1]];
+        assert_eq!(min_sum_solution(&a, &[3, 5]), Some(vec![0, 3, 2]));
+    }
+
+    #[test]
+    fn rejects_unsolvable_systems() {
+        // x + y = 1, x + y = 2
+        assert_eq!(min_sum_solution(&[vec![1, 1], vec![1, 1]], &[1, 2]), None);
+        // 2x = 3
+        assert_eq!(min_sum_solution(&[vec![2]], &[3]), None);
+        // x + y = 1, y = 2 needs x = -1
+        assert_eq!(min_sum_solution(&[vec![1, 1], vec![0, 1]], &[1, 2]), None);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let systems: [(Vec<Vec<u64>>, Vec<u64>); 5] = [
+            (vec![vec![1, 1, 1], vec![0, 1, 2]], vec![6, 4]),
+            (vec![vec![2, 1, 0, 1], vec![0, 1, 3, 1]], vec![7, 9]),
+            (vec![vec![1, 2, 3, 1]], vec![5]),
+            (
+                vec![vec![1, 0, 1, 1], vec![0, 1, 1, 0], vec![1, 1, 0, 1]],
+                vec![4, 3, 5],
+            ),
+            // the first two columns are interchangeable.
+            (vec![vec![1, 1, 2], vec![1, 1, 0]], vec![8, 2]),
+        ];
+
+        for (a, b) in systems {
+            let solution = min_sum_solution(&a, &b);
+            if let Some(x) = &solution {
+                assert_eq!(multiply(&a, x), b);
+            }
+            assert_eq!(
+                solution.map(|x| x.iter().sum()),
+                brute_force(&a, &b, 9),
+                "{a:?} x = {b:?}"
+            );
+        }
+    }
+}
@@ -1,79 +1,225 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, time, verify, watch,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, Year};
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::input::Source;
+    use advent_of_code::template::params::Params;
+    use advent_of_code::template::timings_history::Baseline;
+    use advent_of_code::template::{Day, Year, config};
     use std::process;
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
+        Examples {
+            year: Year,
+            day: Day,
+            yes: bool,
+        },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            input: Source,
+            params: Params,
+            watch: bool,
         },
         All {
+            year: Year,
             release: bool,
+            jobs: Option<usize>,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            warmup: Option<u128>,
+            jobs: Option<usize>,
+            compare: Option<(Baseline, f64)>,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
+            release: bool,
+            jobs: Option<usize>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Read the `--year` option, falling back to the `AOC_YEAR` environment variable and the `year` of `aoc.toml`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        let year = args
+            .opt_value_from_str("--year")?
+            .or_else(Year::from_config);
+        Ok(year.ok_or("no year specified, pass `--year` or set `year` in `aoc.toml`.")?)
+    }
+
+    /// Read the build mode from `--release` or `--debug`, falling back to the `release` setting of `aoc.toml`.
+    fn parse_release(args: &mut pico_args::Arguments) -> Result<bool, Box<dyn std::error::Error>> {
+        match (args.contains("--release"), args.contains("--debug")) {
+            (true, true) => Err("`--release` and `--debug` cannot be combined.".into()),
+            (true, false) => Ok(true),
+            (false, true) => Ok(false),
+            (false, false) => Ok(config::get().release),
+        }
+    }
+
+    /// Check that a day is part of the calendar of the year it is used with.
+    fn check_day(year: Year, day: Day) -> Result<Day, String> {
+        if year.has_day(day) {
+            Ok(day)
+        } else {
+            Err(format!("{year} only has {} days.", year.days()))
+        }
+    }
+
+    /// Read the input a solution runs against from `--input <path>`, `--input -` or `--example`.
+    fn parse_input(args: &mut pico_args::Arguments) -> Result<Source, Box<dyn std::error::Error>> {
+        let input: Option<String> = args.opt_value_from_str("--input")?;
+        let example = args.contains("--example");
+
+        match (input.as_deref(), example) {
+            (Some(_), true) => Err("`--input` and `--example` cannot be combined.".into()),
+            (Some("-"), false) => Ok(Source::Stdin),
+            (Some(path), false) => Ok(Source::File(path.into())),
+            (None, true) => Ok(Source::Example),
+            (None, false) => Ok(Source::Official),
+        }
+    }
+
+    /// Read the parameter overrides passed as `--param key=value`.
+    fn parse_params(args: &mut pico_args::Arguments) -> Result<Params, Box<dyn std::error::Error>> {
+        let mut params = Params::default();
+        for assignment in args.values_from_str::<_, String>("--param")? {
+            let (key, value) = Params::parse_assignment(&assignment)?;
+            params.set(&key, &value);
+        }
+        Ok(params)
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                year: parse_year(&mut args)?,
+                release: parse_release(&mut args)?,
+                jobs: args.opt_value_from_str("--jobs")?,
             },
             Some("time") => {
+                let year = parse_year(&mut args)?;
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let warmup = args.opt_value_from_str("--warmup")?;
+                let jobs = args.opt_value_from_str("--jobs")?;
+                let compare = args.contains("--compare");
+                let baseline = args.opt_value_from_str("--baseline")?;
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let day: Option<Day> = args.opt_free_from_str()?;
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: day.map(|day| check_day(year, day)).transpose()?,
                     store,
+                    warmup,
+                    jobs,
+                    compare: compare
+                        .then(|| (baseline.unwrap_or_default(), threshold.unwrap_or(10.0))),
+                }
+            }
+            Some("verify") => {
+                let year = parse_year(&mut args)?;
+                let release = parse_release(&mut args)?;
+                let jobs = args.opt_value_from_str("--jobs")?;
+                let day: Option<Day> = args.opt_free_from_str()?;
+
+                AppArguments::Verify {
+                    year,
+                    day: day.map(|day| check_day(year, day)).transpose()?,
+                    release,
+                    jobs,
+                }
+            }
+            Some("download") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Download {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                }
+            }
+            Some("read") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Read {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                }
+            }
+            Some("examples") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Examples {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                    yes: args.contains("--yes"),
+                }
+            }
+            Some("scaffold") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Scaffold {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                    download: args.contains("--download"),
+                    overwrite: args.contains("--overwrite"),
+                }
+            }
+            Some("solve") => {
+                let year = parse_year(&mut args)?;
+                let day = check_day(year, args.free_from_str()?)?;
+                let release = parse_release(&mut args)?;
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let input = parse_input(&mut args)?;
+                let params = parse_params(&mut args)?;
+                let watch = args.contains("--watch");
+
+                if watch && (submit.is_some() || dhat) {
+                    return Err("`--watch` can not be combined with `--submit` or `--dhat`.".into());
+                }
+
+                AppArguments::Solve {
+                    year,
+                    day,
+                    release,
+                    dhat,
+                    submit,
+                    input,
+                    params,
+                    watch,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -102,38 +248,71 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                jobs,
+            } => all::handle(year, release, jobs),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                warmup,
+                jobs,
+                compare,
+            } => time::handle(year, day, all, store, warmup, jobs, compare),
+            AppArguments::Verify {
+                year,
+                day,
+                release,
+                jobs,
+            } => verify::handle(year, day, release, jobs),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
+            AppArguments::Examples { year, day, yes } => examples::handle(year, day, yes),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(year, day, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
                 }
             }
             AppArguments::Solve {
+                year,
+                day,
+                release,
+                input,
+                params,
+                watch: true,
+                ..
+            } => watch::handle(year, day, release, &input, &params),
+            AppArguments::Solve {
+                year,
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                input,
+                params,
+                watch: false,
+            } => solve::handle(year, day, release, dhat, submit, &input, &params),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match Year::today().zip(Day::today()) {
+                    Some((year, day)) => {
+                        scaffold::handle(year, day, false);
+                        download::handle(year, day);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 12th of december. Please use `scaffold` with a specific day."
+                            "`today` command can only be run during advent. \
+                            Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,112 @@
+//! Memoization for recursive solutions, scoped to a single input.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A memoization cache that is bound to the input it is computed from.
+///
+/// Unlike a process-global cache, a [`Memo`] lives as long as the solve call that creates it:
+/// benchmark iterations start out with an empty cache, and calls with different inputs can
+/// never read each other's results.
+pub struct Memo<'a, I: ?Sized, K, V> {
+    input: &'a I,
+    cache: HashMap<K, V>,
+}
+
+impl<'a, I: ?Sized, K: Eq + Hash, V: Clone> Memo<'a, I, K, V> {
+    pub fn new(input: &'a I) -> Self {
+        Memo {
+            input,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// The input the cached values are computed from.
+    pub fn input(&self) -> &'a I {
+        self.input
+    }
+
+    /// Returns the cached value for the key, or computes and caches it.
+    /// The computation receives the memo itself, so that it can recurse.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(self, &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// The number of cached values.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Drop all cached values, keeping the input.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Memo;
+
+    fn count(memo: &mut Memo<[u64], usize, u64>, n: usize, calls: &mut u32) -> u64 {
+        memo.get_or_insert_with(n, |memo, &n| {
+            *calls += 1;
+            memo.input()[n]
+        })
+    }
+
+    #[test]
+    fn caches_values() {
+        let input = [10, 20];
+        let mut memo = Memo::new(&input[..]);
+        let mut calls = 0;
+
+        assert_eq!(count(&mut memo, 1, &mut calls), 20);
+        assert_eq!(count(&mut memo, 1, &mut calls), 20);
+        assert_eq!(calls, 1);
+        assert_eq!(memo.len(), 1);
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(count(&mut memo, 1, &mut calls), 20);
+        assert_eq!(calls, 2);
+    }
+
+    fn fib(memo: &mut Memo<[u64], usize, u64>, n: usize) -> u64 {
+        memo.get_or_insert_with(n, |memo, &n| {
+            if n < 2 {
+                memo.input()[n]
+            } else {
+                fib(memo, n - 1) + fib(memo, n - 2)
+            }
+        })
+    }
+
+    #[test]
+    fn caches_recursive_calls() {
+        let seeds = [0, 1];
+        let mut memo = Memo::new(&seeds[..]);
+        assert_eq!(fib(&mut memo, 90), 2_880_067_194_370_816_120);
+        assert_eq!(memo.len(), 91);
+    }
+
+    #[test]
+    fn scopes_caches_to_their_input() {
+        let (a, b) = ([1, 2], [3, 4]);
+        let mut calls = 0;
+
+        assert_eq!(count(&mut Memo::new(&a[..]), 0, &mut calls), 1);
+        assert_eq!(count(&mut Memo::new(&b[..]), 0, &mut calls), 3);
+    }
+}
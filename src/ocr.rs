@@ -0,0 +1,285 @@
+//! Recognition of the capital letters some puzzles render as ASCII art, e.g.
+//!
+//! ```text
+//! #..#.####.#....#.....##.
+//! #..#.#....#....#....#..#
+//! ####.###..#....#....#..#
+//! #..#.#....#....#....#..#
+//! #..#.#....#....#....#..#
+//! #..#.####.####.####..##.
+//! ```
+//!
+//! Both the small font with letters 6 pixels high and the large font with letters 10 pixels high
+//! are supported. Lit pixels are `#` or `█`, dark pixels `.` or spaces.
+
+/// Letters of the small font, 6 pixels high.
+const FONT_6: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters of the large font, 10 pixels high.
+const FONT_10: &[(char, [&str; 10])] = &[
+    (
+        'A',
+        [
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        [
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        [
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        [
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        [
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        [
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        [
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        [
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        [
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        [
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        [
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+/// Decode ASCII art into the letters it shows.
+/// Returns `None` if the text is not ASCII art of a supported height, or shows an unknown letter.
+pub fn decode(art: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|line| line.is_empty())
+        .map(|line| line.chars().map(parse_pixel).collect::<Option<Vec<_>>>())
+        .collect::<Option<_>>()?;
+
+    let rows: Vec<Vec<bool>> = match rows.iter().rposition(|row| !row.is_empty()) {
+        Some(last) => rows[..=last].to_vec(),
+        None => return None,
+    };
+
+    match rows.len() {
+        6 => decode_rows(&rows, FONT_6),
+        10 => decode_rows(&rows, FONT_10),
+        _ => None,
+    }
+}
+
+fn parse_pixel(c: char) -> Option<bool> {
+    match c {
+        '#' | '█' => Some(true),
+        '.' | ' ' => Some(false),
+        _ => None,
+    }
+}
+
+fn decode_rows<const N: usize>(rows: &[Vec<bool>], font: &[(char, [&str; N])]) -> Option<String> {
+    let width = rows.iter().map(Vec::len).max()?;
+    let is_lit = |row: usize, col: usize| rows[row].get(col).copied().unwrap_or(false);
+    let is_empty_column = |col: usize| (0..rows.len()).all(|row| !is_lit(row, col));
+
+    let mut letters = String::new();
+    let mut col = 0;
+
+    while col < width {
+        if is_empty_column(col) {
+            col += 1;
+            continue;
+        }
+
+        let start = col;
+        while col < width && !is_empty_column(col) {
+            col += 1;
+        }
+
+        let glyph: Vec<Vec<bool>> = (0..rows.len())
+            .map(|row| (start..col).map(|c| is_lit(row, c)).collect())
+            .collect();
+
+        let (letter, _) = font
+            .iter()
+            .find(|(_, pixels)| trim_columns(pixels) == glyph)?;
+        letters.push(*letter);
+    }
+
+    (!letters.is_empty()).then_some(letters)
+}
+
+/// The pixels of a letter of a font, without its empty columns on both sides.
+fn trim_columns(pixels: &[&str]) -> Vec<Vec<bool>> {
+    let rows: Vec<Vec<bool>> = pixels
+        .iter()
+        .map(|row| row.chars().map(|c| c == '#').collect())
+        .collect();
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let is_used = |col: usize| rows.iter().any(|row| row[col]);
+
+    let Some(first) = (0..width).find(|&col| is_used(col)) else {
+        return rows;
+    };
+    let last = (0..width).rfind(|&col| is_used(col)).unwrap_or(first);
+
+    rows.iter().map(|row| row[first..=last].to_vec()).collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::decode;
+
+    #[test]
+    fn decodes_small_font() {
+        let art = "\
+#..#.####.#....#.....##.
+#..#.#....#....#....#..#
+####.###..#....#....#..#
+#..#.#....#....#....#..#
+#..#.#....#....#....#..#
+#..#.####.####.####..##.";
+        assert_eq!(decode(art), Some("HELLO".into()));
+    }
+
+    #[test]
+    fn decodes_narrow_letters() {
+        let art = "\
+.###..#...#
+..#...#...#
+..#....#.#.
+..#.....#..
+..#.....#..
+.###....#..";
+        assert_eq!(decode(art), Some("IY".into()));
+    }
+
+    #[test]
+    fn decodes_large_font() {
+        let art = "
+#....#..######
+#....#..#.....
+.#..#...#.....
+.#..#...#.....
+..##....#####.
+..##....#.....
+.#..#...#.....
+.#..#...#.....
+#....#..#.....
+#....#..######
+";
+        assert_eq!(decode(art), Some("XE".into()));
+    }
+
+    #[test]
+    fn decodes_block_characters() {
+        let art = " ██\n█  █\n█  █\n████\n█  █\n█  █ ";
+        assert_eq!(decode(art), Some("A".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_art() {
+        assert_eq!(decode("#.#\n.#.\n#.#"), None);
+        assert_eq!(decode("12\n34"), None);
+        assert_eq!(decode("####\n####\n####\n####\n####\n####"), None);
+    }
+}
@@ -0,0 +1,202 @@
+/// Manifest of accepted answers, used by `cargo verify` to catch regressions.
+/// Stored per year as JSON that maps days to their parts, e.g. `{ "01": { "part_1": "3", "part_2": "6" } }`.
+use std::{
+    collections::BTreeMap, collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year, config};
+
+fn get_file_path(year: Year) -> PathBuf {
+    config::get().year_dir(year).join("answers.json")
+}
+
+/// Accepted answers, keyed by day and part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub data: BTreeMap<(Day, u8), String>,
+}
+
+/// Outcome of comparing a result against the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    /// The result matches the accepted answer.
+    Pass,
+    /// The result differs from the accepted answer, or the part did not return a result.
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// There is no accepted answer for this part yet.
+    Unknown,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(get_file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_file_path(year)) {
+            Ok(contents) => Answers::try_from(contents),
+            Err(_) => Ok(Answers::default()),
+        }
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> Option<&String> {
+        self.data.get(&(day, part))
+    }
+
+    pub fn set(&mut self, day: Day, part: u8, answer: &str) {
+        self.data.insert((day, part), answer.to_string());
+    }
+
+    /// Compare the result of a part against its accepted answer.
+    pub fn check(&self, day: Day, part: u8, actual: Option<&str>) -> Check {
+        match self.get(day, part) {
+            None => Check::Unknown,
+            Some(expected) if actual == Some(expected.as_str()) => Check::Pass,
+            Some(expected) => Check::Fail {
+                expected: expected.clone(),
+                actual: actual.map(ToString::to_string),
+            },
+        }
+    }
+}
+
+/// Record an accepted answer in the manifest file.
+pub fn store_accepted(year: Year, day: Day, part: u8, answer: &str) -> Result<(), String> {
+    let mut answers = Answers::read_from_file(year)?;
+    answers.set(day, part, answer);
+    answers.store_file(year).map_err(|e| e.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answers> for JsonValue {
+    fn from(value: &Answers) -> Self {
+        let mut days: HashMap<String, JsonValue> = HashMap::new();
+
+        for ((day, part), answer) in &value.data {
+            let entry = days
+                .entry(day.to_string())
+                .or_insert_with(|| JsonValue::Object(HashMap::new()));
+
+            if let JsonValue::Object(parts) = entry {
+                parts.insert(format!("part_{part}"), JsonValue::String(answer.clone()));
+            }
+        }
+
+        JsonValue::Object(days)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let days = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut data = BTreeMap::new();
+
+        for (day_str, parts) in days {
+            let day = Day::from_str(day_str).map_err(|e| format!("answers.{day_str}: {e}."))?;
+
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("expected answers.{day_str} to be an object."))?;
+
+            for (part_str, answer) in parts {
+                let part = match part_str.as_str() {
+                    "part_1" => 1,
+                    "part_2" => 2,
+                    _ => return Err(format!("unexpected key answers.{day_str}.{part_str}.")),
+                };
+
+                // NOTE: allow numeric answers, which are the common case when editing by hand.
+                let answer = match answer {
+                    JsonValue::String(s) => s.clone(),
+                    JsonValue::Number(n) if n.fract() == 0.0 => format!("{n:.0}"),
+                    _ => {
+                        return Err(format!(
+                            "expected answers.{day_str}.{part_str} to be a string."
+                        ));
+                    }
+                };
+
+                data.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Answers, Check};
+    use crate::day;
+
+    fn get_mock_answers() -> Answers {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "3");
+        answers.set(day!(1), 2, "6");
+        answers.set(day!(8), 1, "40");
+        answers
+    }
+
+    #[test]
+    fn round_trips_answers() {
+        let answers = get_mock_answers();
+        let json = JsonValue::from(&answers).stringify().unwrap();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+    }
+
+    #[test]
+    fn handles_numeric_answers() {
+        let json = r#"{ "03": { "part_1": 357, "part_2": "3121910778619" } }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(3), 1), Some(&"357".to_string()));
+        assert_eq!(answers.get(day!(3), 2), Some(&"3121910778619".to_string()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_unknown_parts() {
+        let json = r#"{ "03": { "part_3": "1" } }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn checks_results() {
+        let answers = get_mock_answers();
+        assert_eq!(answers.check(day!(1), 1, Some("3")), Check::Pass);
+        assert_eq!(
+            answers.check(day!(1), 2, Some("7")),
+            Check::Fail {
+                expected: "6".into(),
+                actual: Some("7".into())
+            }
+        );
+        assert_eq!(
+            answers.check(day!(8), 1, None),
+            Check::Fail {
+                expected: "40".into(),
+                actual: None
+            }
+        );
+        assert_eq!(answers.check(day!(8), 2, Some("1")), Check::Unknown);
+    }
+}
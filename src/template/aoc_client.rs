@@ -0,0 +1,402 @@
+/// Native HTTP client for adventofcode.com, used in place of aoc-cli when the `native-client`
+/// feature is enabled.
+///
+/// The session cookie is read from the `AOC_SESSION` environment variable or from the file that
+/// aoc-cli uses (`~/.adventofcode.session`). The base URL can be overridden with `AOC_BASE_URL`.
+use std::{env, fmt::Display, fs, path::PathBuf};
+
+use crate::template::{
+    Day, Year,
+    aoc_cli::{get_input_path, get_puzzle_path},
+};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str =
+    "advent_of_code template (+https://github.com/fspoettel/advent-of-code-rust)";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    Http(String),
+    BadStatus(u16),
+    Io(std::io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "session cookie not found. Set AOC_SESSION or write it to ~/.adventofcode.session."
+            ),
+            AocClientError::Http(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for AocClientError {
+    fn from(e: std::io::Error) -> Self {
+        AocClientError::Io(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Http(e.to_string()),
+        }
+    }
+}
+
+pub struct Client {
+    pub base_url: String,
+    pub session: String,
+    pub year: Year,
+}
+
+impl Client {
+    /// Build a client for the puzzles of a year, reading the session and base URL from the environment.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+
+        Ok(Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            year,
+        })
+    }
+
+    /// Fetch the personal puzzle input.
+    pub fn get_input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetch the puzzle description, converted to markdown.
+    pub fn get_puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(day))?;
+        Ok(html_to_markdown(&html))
+    }
+
+    /// Post an answer and return the response message as text.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<String, AocClientError> {
+        let html = self
+            .request("POST", &format!("{}/answer", self.day_url(day)))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+        Ok(html_to_markdown(&html))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        Ok(self.request("GET", url).call()?.into_string()?)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        ureq::request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+    }
+}
+
+fn get_session() -> Option<String> {
+    let session = env::var("AOC_SESSION").ok().or_else(|| {
+        let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+        fs::read_to_string(PathBuf::from(home).join(".adventofcode.session")).ok()
+    })?;
+
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+pub fn read(year: Year, day: Day) -> Result<(), AocClientError> {
+    let puzzle = Client::from_env(year)?.get_puzzle(day)?;
+    fs::write(get_puzzle_path(year, day), &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+pub fn download(year: Year, day: Day) -> Result<(), AocClientError> {
+    let client = Client::from_env(year)?;
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
+
+    fs::write(&input_path, client.get_input(day)?)?;
+    fs::write(&puzzle_path, client.get_puzzle(day)?)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn submit(year: Year, day: Day, part: u8, result: &str) -> Result<String, AocClientError> {
+    let message = Client::from_env(year)?.submit(day, part, result)?;
+    println!("{message}");
+    Ok(message)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Convert the `<article>` elements of a page to markdown.
+/// Only handles the small subset of HTML that puzzle pages use.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut articles = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let body = &rest[start..];
+        let body = &body[body.find('>').map_or(body.len(), |i| i + 1)..];
+        let end = body.find("</article>").unwrap_or(body.len());
+        articles.push(convert_fragment(&body[..end]));
+        rest = &body[end..];
+    }
+
+    articles.join("\n\n").trim().to_string() + "\n"
+}
+
+fn convert_fragment(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(tag) = rest.strip_prefix('<') {
+            let end = tag.find('>').unwrap_or(tag.len());
+            let (name, attrs, closing) = parse_tag(&tag[..end]);
+            rest = tag.get(end + 1..).unwrap_or("");
+
+            match (name.as_str(), closing) {
+                ("pre", false) => {
+                    in_pre = true;
+                    out.push_str("\n```\n");
+                }
+                ("pre", true) => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                // NOTE: formatting inside code blocks can not be expressed in markdown.
+                _ if in_pre => {}
+                ("h2", false) => out.push_str("## "),
+                ("h2" | "p", true) => out.push_str("\n\n"),
+                ("code", _) => out.push('`'),
+                ("em", _) => out.push('*'),
+                ("li", false) => out.push_str("- "),
+                ("li", true) => out.push('\n'),
+                ("ul", true) => out.push('\n'),
+                ("a", false) => {
+                    links.push(get_attr(attrs, "href").unwrap_or_default());
+                    out.push('[');
+                }
+                ("a", true) => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({href})"));
+                }
+                _ => {}
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = decode_entities(&rest[..end]);
+            // NOTE: whitespace between block elements is noise, between inline elements it is not.
+            if in_pre || !text.trim().is_empty() || !text.contains('\n') {
+                out.push_str(&text);
+            }
+            rest = &rest[end..];
+        }
+    }
+
+    collapse_blank_lines(&out)
+}
+
+fn parse_tag(tag: &str) -> (String, &str, bool) {
+    let (closing, tag) = match tag.strip_prefix('/') {
+        Some(tag) => (true, tag),
+        None => (false, tag),
+    };
+    let tag = tag.trim_end_matches('/');
+    let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+    (name.to_lowercase(), attrs, closing)
+}
+
+fn get_attr(attrs: &str, name: &str) -> Option<String> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = attrs[start..].find('"')?;
+    Some(decode_entities(&attrs[start..start + end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn collapse_blank_lines(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank_lines = 0;
+
+    for line in text.lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 || out.is_empty() {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    out.trim_end().to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{Client, html_to_markdown};
+    use crate::{day, year};
+
+    const PUZZLE_HTML: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Read the <a href="/2025/about">docs</a> &amp; count &lt;things&gt;.</p>
+<pre><code>1 2
+<em>3</em> 4
+</code></pre>
+<ul><li>The answer is <code><em>42</em></code> <em>exactly</em>.</li></ul>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now <em>twice</em>.</p></article>
+</main></body></html>"#;
+
+    const EXPECTED_MARKDOWN: &str = "## --- Day 1: Test ---
+
+Read the [docs](/2025/about) & count <things>.
+
+```
+1 2
+3 4
+```
+
+- The answer is `*42*` *exactly*.
+
+## --- Part Two ---
+
+Now *twice*.
+";
+
+    /// Serve a fixed body for `count` requests, forwarding the raw requests to the receiver.
+    fn mock_server(body: &'static str, count: usize) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().take(count) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut payload = vec![0; content_length];
+                reader.read_exact(&mut payload).unwrap();
+                request.push_str(&String::from_utf8(payload).unwrap());
+                tx.send(request).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+
+    fn get_client(base_url: String) -> Client {
+        Client {
+            base_url,
+            session: "abc123".into(),
+            year: year!(2025),
+        }
+    }
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        assert_eq!(html_to_markdown(PUZZLE_HTML), EXPECTED_MARKDOWN);
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, requests) = mock_server("1 2\n3 4\n", 1);
+        let input = get_client(base_url).get_input(day!(1)).unwrap();
+        assert_eq!(input, "1 2\n3 4\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc123"));
+    }
+
+    #[test]
+    fn downloads_puzzle() {
+        let (base_url, requests) = mock_server(PUZZLE_HTML, 1);
+        let puzzle = get_client(base_url).get_puzzle(day!(12)).unwrap();
+        assert_eq!(puzzle, EXPECTED_MARKDOWN);
+        assert!(
+            requests
+                .recv()
+                .unwrap()
+                .starts_with("GET /2025/day/12 HTTP/1.1")
+        );
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (base_url, requests) = mock_server(
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+            1,
+        );
+        let message = get_client(base_url).submit(day!(3), 2, "357").unwrap();
+        assert!(message.starts_with("That's the right answer!"));
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/3/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=357"));
+    }
+}
@@ -1,7 +1,10 @@
-use crate::template::{Day, aoc_cli};
+use crate::template::{Day, Year};
 use std::process;
 
-pub fn handle(day: Day) {
+#[cfg(not(feature = "native-client"))]
+pub fn handle(year: Year, day: Day) {
+    use crate::template::aoc_cli;
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -9,8 +12,18 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    if let Err(e) = aoc_cli::download(year, day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
 }
+
+#[cfg(feature = "native-client")]
+pub fn handle(year: Year, day: Day) {
+    use crate::template::aoc_client;
+
+    if let Err(e) = aoc_client::download(year, day) {
+        eprintln!("failed to download day {day}: {e}");
+        process::exit(1);
+    };
+}
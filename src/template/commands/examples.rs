@@ -0,0 +1,135 @@
+use std::{
+    fs,
+    io::{self, Write},
+    process,
+};
+
+use crate::template::aoc_cli::get_puzzle_path;
+use crate::template::examples::{self, Example};
+use crate::template::{Day, Year, config};
+
+pub fn handle(year: Year, day: Day, yes: bool) {
+    let puzzle_path = get_puzzle_path(year, day);
+    let module_path = format!("src/bin/{year}-{day}.rs");
+
+    let Ok(markdown) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read puzzle description \"{puzzle_path}\". Try running `cargo download {day}` first."
+        );
+        process::exit(1);
+    };
+
+    let examples = examples::extract(&markdown);
+    let files = get_example_files(year, day, &examples);
+
+    if files.is_empty() && examples.iter().all(|x| x.answer.is_none()) {
+        eprintln!("Could not find any examples in \"{puzzle_path}\".");
+        process::exit(1);
+    }
+
+    for (path, input) in &files {
+        println!("Example \"{path}\":\n{input}");
+    }
+
+    for example in &examples {
+        match &example.answer {
+            Some(answer) => println!("Part {}: expecting `{answer}`.", example.part),
+            None => println!("Part {}: no expected answer found.", example.part),
+        }
+    }
+
+    if !yes && !confirm("Write example files and expected answers to the tests?") {
+        return;
+    }
+
+    for (path, input) in &files {
+        write_example(path, input);
+    }
+
+    let has_part_example = files.len() > 1;
+    fill_tests(&module_path, &examples, has_part_example);
+}
+
+/// The example files to write. If part two has an example of its own, it goes to a part file.
+fn get_example_files(year: Year, day: Day, examples: &[Example]) -> Vec<(String, String)> {
+    let mut files = Vec::new();
+    let examples_dir = config::get().year_dir(year).join("examples");
+    let path = |name: String| examples_dir.join(name).display().to_string();
+
+    let part_one = examples.first().and_then(|x| x.input.as_ref());
+    let part_two = examples.get(1).and_then(|x| x.input.as_ref());
+
+    if let Some(input) = part_one {
+        files.push((path(format!("{day}.txt")), input.clone()));
+    }
+
+    if let Some(input) = part_two
+        && part_one.is_some_and(|x| x != input)
+    {
+        files.push((path(format!("{day}-2.txt")), input.clone()));
+    }
+
+    files
+}
+
+fn confirm(question: &str) -> bool {
+    print!("{question} [y/N] ");
+    io::stdout().flush().unwrap();
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).is_ok() && answer.trim().eq_ignore_ascii_case("y")
+}
+
+/// Write an example file, leaving files alone that were already filled in.
+fn write_example(path: &str, input: &str) {
+    match fs::read_to_string(path) {
+        Ok(contents) if contents == input => {}
+        Ok(contents) if !contents.trim().is_empty() => {
+            println!("Skipped example file \"{path}\", it already has other contents.");
+        }
+        _ => match fs::write(path, input) {
+            Ok(()) => println!("Wrote example file \"{path}\""),
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        },
+    }
+}
+
+/// Fill the expected answers into the tests of a module that still assert `None`.
+fn fill_tests(path: &str, examples: &[Example], has_part_example: bool) {
+    let Ok(mut source) = fs::read_to_string(path) else {
+        eprintln!("Could not read module file \"{path}\", skipped updating its tests.");
+        return;
+    };
+
+    let mut filled = false;
+
+    for example in examples {
+        let Some(answer) = &example.answer else {
+            continue;
+        };
+
+        let has_part_example = has_part_example && example.part == 2;
+        match examples::fill_test(&source, example.part, answer, has_part_example) {
+            Some(updated) => {
+                source = updated;
+                filled = true;
+                println!(
+                    "Filled expected answer of part {} into \"{path}\"",
+                    example.part
+                );
+            }
+            None => println!(
+                "Skipped test of part {}, it is already filled in.",
+                example.part
+            ),
+        }
+    }
+
+    if filled && let Err(e) = fs::write(path, source) {
+        eprintln!("Failed to write module file: {e}");
+        process::exit(1);
+    }
+}
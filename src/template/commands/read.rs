@@ -1,8 +1,11 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{Day, Year};
+
+#[cfg(not(feature = "native-client"))]
+pub fn handle(year: Year, day: Day) {
+    use crate::template::aoc_cli;
 
-pub fn handle(day: Day) {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -10,8 +13,18 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    if let Err(e) = aoc_cli::read(year, day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
 }
+
+#[cfg(feature = "native-client")]
+pub fn handle(year: Year, day: Day) {
+    use crate::template::aoc_client;
+
+    if let Err(e) = aoc_client::read(year, day) {
+        eprintln!("failed to read day {day}: {e}");
+        process::exit(1);
+    };
+}
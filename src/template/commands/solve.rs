@@ -1,9 +1,23 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::input::Source;
+use crate::template::params::Params;
+use crate::template::{Day, Year};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    input: &Source,
+    params: &Params,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        format!("{year}-{day}"),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -18,6 +32,24 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd_args.push("--".to_string());
 
+    match input {
+        Source::Official => {}
+        Source::Example => cmd_args.push("--example".to_string()),
+        Source::File(path) => {
+            cmd_args.push("--input".to_string());
+            cmd_args.push(path.to_string_lossy().into_owned());
+        }
+        Source::Stdin => {
+            cmd_args.push("--input".to_string());
+            cmd_args.push("-".to_string());
+        }
+    }
+
+    for (key, value) in params.iter() {
+        cmd_args.push("--param".to_string());
+        cmd_args.push(format!("{key}={value}"));
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
@@ -1,19 +1,35 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::report::Step;
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::timings_history::{Baseline, Comparison, History};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Days are benched sequentially unless `jobs` is passed, so that concurrent days do not disturb the timings.
+///
+/// With `compare`, the run is compared against the timings history, exiting with a non-zero status
+/// if a step is more than the threshold (in percent) slower than its baseline. Steps without history
+/// are compared against `timings.json` instead.
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    warmup: Option<u128>,
+    jobs: Option<usize>,
+    compare: Option<(Baseline, f64)>,
+) {
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
-                all_days().collect()
+            if run_all || compare.is_some() {
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
+                all_days(year)
                     .filter(|day| !stored_timings.is_day_complete(*day))
                     .collect()
             }
@@ -21,14 +37,34 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let (timings, records) = run_multi(year, &days_to_run, true, true, warmup, jobs).unwrap();
+
+    let mut has_regression = false;
+
+    if let Some((baseline, threshold)) = compare {
+        let history = match History::read_from_file(year) {
+            Ok(history) => history,
+            Err(e) => {
+                eprintln!("Failed to read timings history: {e}");
+                process::exit(1);
+            }
+        };
+
+        let comparisons = history.compare(&records, &stored_timings, baseline, threshold);
+        print_comparisons(&comparisons, baseline, threshold);
+        has_regression = comparisons.iter().any(|c| c.is_regression);
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
+
+        if let Err(e) = History::append_to_file(year, &records) {
+            eprintln!("Failed to append to timings history: {e}");
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +73,44 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if has_regression {
+        process::exit(1);
+    }
+}
+
+fn print_comparisons(comparisons: &[Comparison], baseline: Baseline, threshold: f64) {
+    println!();
+    println!(
+        "{ANSI_BOLD}Comparison{ANSI_RESET} (against {baseline} stored timing, threshold {threshold}%)"
+    );
+    println!();
+
+    if comparisons.is_empty() {
+        println!("No stored timings to compare against.");
+        return;
+    }
+
+    println!("| Day | Step | Baseline | Current | Change |");
+    println!("| :---: | :---: | :---: | :---: | :---: |");
+
+    for c in comparisons {
+        let step = match c.step {
+            Step::Parse => "Parse".to_string(),
+            Step::Part(part) => format!("Part {part}"),
+        };
+        let marker = if c.is_regression { "✖ " } else { "" };
+
+        println!(
+            "| Day {} | {step} | `{:.1?}` | `{:.1?}` | {marker}{:+.1}% |",
+            c.day, c.baseline, c.current, c.change
+        );
+    }
+
+    let regressions = comparisons.iter().filter(|c| c.is_regression).count();
+    println!();
+    println!(
+        "{regressions} regressions in {} compared steps.",
+        comparisons.len()
+    );
 }
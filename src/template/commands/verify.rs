@@ -0,0 +1,79 @@
+use std::process;
+
+use crate::template::answers::{Answers, Check};
+use crate::template::report::Step;
+use crate::template::run_multi::child_commands;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days};
+
+pub fn handle(year: Year, day: Option<Day>, is_release: bool, jobs: Option<usize>) {
+    let answers = match Answers::read_from_file(year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read answers manifest: {e}");
+            process::exit(1);
+        }
+    };
+
+    let days: Vec<Day> = day.map_or_else(|| all_days(year).collect(), |day| vec![day]);
+
+    let records = match child_commands::run_solutions(year, &days, false, is_release, None, jobs) {
+        Ok(records) => records,
+        Err(e) => {
+            eprintln!("Failed to run solutions: {e:?}");
+            process::exit(1);
+        }
+    };
+
+    println!();
+    println!("{ANSI_BOLD}Verification{ANSI_RESET}");
+    println!();
+    println!("| Day | Part 1 | Part 2 |");
+    println!("| :---: | :---: | :---: |");
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+
+    // NOTE: days that are not solved or crashed do not report any records,
+    // they are only listed if they have known answers to fail against.
+    let days_to_check = days.iter().filter(|day| {
+        records.iter().any(|r| r.day == **day) || answers.data.keys().any(|(d, _)| d == *day)
+    });
+
+    for day in days_to_check {
+        let cells: Vec<String> = [1, 2]
+            .iter()
+            .map(|&part| {
+                let actual = records
+                    .iter()
+                    .find(|r| r.day == *day && r.step == Step::Part(part))
+                    .and_then(|r| r.answer.as_deref());
+
+                match answers.check(*day, part, actual) {
+                    Check::Pass => {
+                        passed += 1;
+                        "✔".to_string()
+                    }
+                    Check::Fail { expected, actual } => {
+                        failed += 1;
+                        format!(
+                            "✖ expected `{expected}`, got `{}`",
+                            actual.unwrap_or_else(|| "-".into())
+                        )
+                    }
+                    Check::Unknown => {
+                        unknown += 1;
+                        "?".to_string()
+                    }
+                }
+            })
+            .collect();
+
+        println!("| Day {day} | {} | {} |", cells[0], cells[1]);
+    }
+
+    println!();
+    println!("{passed} passed, {failed} failed, {unknown} without known answer.");
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
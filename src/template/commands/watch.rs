@@ -0,0 +1,167 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    thread,
+    time::Duration,
+};
+
+use crate::template::input::Source;
+use crate::template::params::Params;
+use crate::template::report::{self, Record};
+use crate::template::watch::{AnswerChange, Snapshot, diff_answers, source_files};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Year, config};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Re-run the tests of a day and its solution whenever its module, the library, the template or its
+/// data changes. Polls the filesystem, so it works without any file notification support.
+pub fn handle(year: Year, day: Day, release: bool, input: &Source, params: &Params) {
+    if *input == Source::Stdin {
+        eprintln!(
+            "`--watch` can not read the input from stdin, pass a file with `--input <path>`."
+        );
+        process::exit(1);
+    }
+
+    let paths = get_watched_paths(year, day, input);
+    let mut snapshot = Snapshot::take(&paths);
+    let mut previous: Option<Vec<Record>> = None;
+
+    println!(
+        "Watching {} files for day {day}. Press Ctrl-C to stop.",
+        paths.len()
+    );
+
+    loop {
+        let records = run(year, day, release, input, params);
+        print_answers(previous.as_deref(), &records);
+        previous = Some(records);
+
+        println!("{ANSI_ITALIC}Waiting for changes...{ANSI_RESET}");
+
+        let changes = loop {
+            thread::sleep(POLL_INTERVAL);
+            // the paths are collected again, so that new library modules are picked up.
+            let current = Snapshot::take(&get_watched_paths(year, day, input));
+            let changes: Vec<String> = current
+                .changes_since(&snapshot)
+                .iter()
+                .map(|x| x.to_string_lossy().into_owned())
+                .collect();
+            snapshot = current;
+
+            if !changes.is_empty() {
+                break changes;
+            }
+        };
+
+        println!();
+        println!("{ANSI_BOLD}Changed:{ANSI_RESET} {}", changes.join(", "));
+    }
+}
+
+fn get_watched_paths(year: Year, day: Day, input: &Source) -> Vec<PathBuf> {
+    let mut paths = source_files(Path::new("src"));
+    paths.push(PathBuf::from(format!("src/bin/{year}-{day}.rs")));
+
+    let data = config::get().year_dir(year);
+    for name in [day.to_string(), format!("{day}-1"), format!("{day}-2")] {
+        paths.push(data.join("examples").join(format!("{name}.txt")));
+        paths.push(data.join("examples").join(format!("{name}.params")));
+    }
+
+    match input {
+        Source::File(path) => paths.push(path.clone()),
+        _ => paths.push(data.join("inputs").join(format!("{day}.txt"))),
+    }
+
+    paths
+}
+
+/// Run the example tests of the day, then the solution, and collect the records it reported.
+fn run(year: Year, day: Day, release: bool, input: &Source, params: &Params) -> Vec<Record> {
+    let bin = format!("{year}-{day}");
+
+    println!("{ANSI_BOLD}Tests{ANSI_RESET}");
+    let tests = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &bin])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output();
+
+    match tests {
+        Ok(output) if output.status.success() => println!("✔ passed"),
+        Ok(output) => {
+            // NOTE: passing runs are kept compact, failing ones show the full output.
+            print!("{}", String::from_utf8_lossy(&output.stdout));
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            println!("✖ failed");
+        }
+        Err(e) => {
+            eprintln!("Failed to run tests: {e}");
+            process::exit(1);
+        }
+    }
+
+    println!("{ANSI_BOLD}Solution{ANSI_RESET}");
+
+    let report_path = env::temp_dir().join(format!("aoc-watch-{}.jsonl", process::id()));
+    let _ = fs::remove_file(&report_path);
+
+    let mut args = vec!["run".to_string(), "--quiet".to_string()];
+    if release {
+        args.push("--release".to_string());
+    }
+    args.extend(["--bin".to_string(), bin, "--".to_string()]);
+
+    match input {
+        Source::Example => args.push("--example".to_string()),
+        Source::File(path) => {
+            args.push("--input".to_string());
+            args.push(path.to_string_lossy().into_owned());
+        }
+        Source::Official | Source::Stdin => {}
+    }
+
+    for (key, value) in params.iter() {
+        args.push("--param".to_string());
+        args.push(format!("{key}={value}"));
+    }
+
+    args.push("--report".to_string());
+    args.push(report_path.to_string_lossy().into_owned());
+
+    if let Err(e) = Command::new("cargo")
+        .args(&args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+    {
+        eprintln!("Failed to run solution: {e}");
+        process::exit(1);
+    }
+
+    let records = report::read(&report_path).unwrap_or_default();
+    let _ = fs::remove_file(&report_path);
+    records
+}
+
+fn print_answers(previous: Option<&[Record]>, records: &[Record]) {
+    println!("{ANSI_BOLD}Answers{ANSI_RESET}");
+
+    let format = |answer: &Option<String>| answer.clone().unwrap_or_else(|| "✖".into());
+
+    for (part, change) in diff_answers(previous, records) {
+        let line = match change {
+            AnswerChange::New(current) => format(&current),
+            AnswerChange::Unchanged(current) => format!("{} (unchanged)", format(&current)),
+            AnswerChange::Changed { previous, current } => {
+                format!("{} → {}", format(&previous), format(&current))
+            }
+            AnswerChange::Missing(previous) => format!("{} → no answer", format(&previous)),
+        };
+
+        println!("Part {part}: {line}");
+    }
+}
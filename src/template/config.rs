@@ -0,0 +1,342 @@
+/// Project configuration, read from `aoc.toml` in the working directory.
+///
+/// All settings are optional and fall back to the defaults below, so a missing file configures
+/// the template as before:
+///
+/// ```toml
+/// year = 2025
+/// release = false
+/// data_dir = "data"
+///
+/// [readme]
+/// path = "README.md"
+/// marker = "<!--- benchmarking table --->"
+///
+/// [bench]
+/// min_samples = 10
+/// max_samples = 10000
+/// ```
+///
+/// Only the subset of TOML needed for this is supported: tables, comments and `key = value` pairs
+/// with string, integer or boolean values.
+use std::{collections::BTreeMap, fs, io, path::PathBuf, process, str::FromStr, sync::OnceLock};
+
+use crate::template::Year;
+
+const CONFIG_FILE_PATH: &str = "aoc.toml";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The year commands run for if neither `--year` nor `AOC_YEAR` is set.
+    pub year: Option<Year>,
+    /// Whether `solve`, `all` and `verify` build in release mode unless `--release` or `--debug` is passed.
+    pub release: bool,
+    /// The folder that holds the inputs, examples, puzzles and manifests of every year.
+    pub data_dir: PathBuf,
+    /// The readme that `cargo time --store` writes the benchmark tables to.
+    pub readme_path: PathBuf,
+    /// The marker that encloses the benchmark table of every year in the readme, with the year added.
+    pub readme_marker: String,
+    /// Lower bound of the number of samples collected when benching a part.
+    pub bench_min_samples: u128,
+    /// Upper bound of the number of samples collected when benching a part.
+    pub bench_max_samples: u128,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: None,
+            release: false,
+            data_dir: PathBuf::from("data"),
+            readme_path: PathBuf::from("README.md"),
+            readme_marker: "<!--- benchmarking table --->".into(),
+            bench_min_samples: 10,
+            bench_max_samples: 10000,
+        }
+    }
+}
+
+/// The configuration of the project, read once per process.
+/// Exits the process if `aoc.toml` exists but is invalid, as no command can run with it.
+pub fn get() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+
+    CONFIG.get_or_init(|| {
+        Config::read_from_file().unwrap_or_else(|e| {
+            eprintln!("Failed to read {CONFIG_FILE_PATH}: {e}");
+            process::exit(1);
+        })
+    })
+}
+
+impl Config {
+    /// Read the configuration from `aoc.toml`. If not present, returns the defaults.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(CONFIG_FILE_PATH) {
+            Ok(contents) => contents.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// The data folder of a year, e.g. `data/2025`.
+    pub fn year_dir(&self, year: Year) -> PathBuf {
+        self.data_dir.join(year.to_string())
+    }
+}
+
+impl FromStr for Config {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config = Config::default();
+
+        for (key, (line, value)) in parse_toml(s)? {
+            let invalid = |expected: &str| format!("line {line}: `{key}` expects {expected}.");
+
+            match key.as_str() {
+                "year" => {
+                    let year = value
+                        .as_integer()
+                        .and_then(|x| u16::try_from(x).ok())
+                        .and_then(Year::new)
+                        .ok_or_else(|| invalid("a year of advent"))?;
+                    config.year = Some(year);
+                }
+                "release" => {
+                    config.release = value.as_bool().ok_or_else(|| invalid("a boolean"))?
+                }
+                "data_dir" => {
+                    config.data_dir = value.as_str().ok_or_else(|| invalid("a path"))?.into();
+                }
+                "readme.path" => {
+                    config.readme_path = value.as_str().ok_or_else(|| invalid("a path"))?.into();
+                }
+                "readme.marker" => {
+                    config.readme_marker = value
+                        .as_str()
+                        .filter(|x| !x.is_empty())
+                        .ok_or_else(|| invalid("a non-empty string"))?
+                        .into();
+                }
+                "bench.min_samples" | "bench.max_samples" => {
+                    let samples = value
+                        .as_integer()
+                        .and_then(|x| u128::try_from(x).ok())
+                        .filter(|&x| x > 0)
+                        .ok_or_else(|| invalid("a positive integer"))?;
+                    if key == "bench.min_samples" {
+                        config.bench_min_samples = samples;
+                    } else {
+                        config.bench_max_samples = samples;
+                    }
+                }
+                _ => return Err(format!("line {line}: unknown setting `{key}`.")),
+            }
+        }
+
+        if config.bench_min_samples > config.bench_max_samples {
+            return Err("`bench.min_samples` must not exceed `bench.max_samples`.".into());
+        }
+
+        Ok(config)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Value {
+    String(String),
+    Integer(i64),
+    Bool(bool),
+}
+
+impl Value {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_integer(&self) -> Option<i64> {
+        match self {
+            Value::Integer(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(x) => Some(*x),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the supported subset of TOML into values keyed by their dotted path, e.g. `bench.min_samples`,
+/// along with the line they were set on.
+fn parse_toml(s: &str) -> Result<BTreeMap<String, (usize, Value)>, String> {
+    let mut values = BTreeMap::new();
+    let mut table = String::new();
+
+    for (index, line) in s.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[') {
+            let name = strip_comment(name)
+                .strip_suffix(']')
+                .map(str::trim)
+                .filter(|x| !x.is_empty())
+                .ok_or(format!("line {line_number}: malformed table header."))?;
+            table = name.to_string();
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or(format!("line {line_number}: expected `key = value`."))?;
+
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(format!("line {line_number}: missing key."));
+        }
+
+        let value = parse_value(value.trim()).map_err(|e| format!("line {line_number}: {e}"))?;
+
+        let key = if table.is_empty() {
+            key.to_string()
+        } else {
+            format!("{table}.{key}")
+        };
+
+        if values.insert(key.clone(), (line_number, value)).is_some() {
+            return Err(format!("line {line_number}: `{key}` is set twice."));
+        }
+    }
+
+    Ok(values)
+}
+
+fn parse_value(s: &str) -> Result<Value, String> {
+    if let Some(rest) = s.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = rest.chars();
+
+        loop {
+            match chars.next() {
+                Some('"') => break,
+                Some('\\') => match chars.next() {
+                    Some('"') => value.push('"'),
+                    Some('\\') => value.push('\\'),
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    _ => return Err("unsupported escape sequence.".into()),
+                },
+                Some(c) => value.push(c),
+                None => return Err("unterminated string.".into()),
+            }
+        }
+
+        if !strip_comment(chars.as_str()).is_empty() {
+            return Err("unexpected characters after string.".into());
+        }
+
+        return Ok(Value::String(value));
+    }
+
+    match strip_comment(s) {
+        "true" => Ok(Value::Bool(true)),
+        "false" => Ok(Value::Bool(false)),
+        value => value
+            .replace('_', "")
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| format!("unsupported value `{value}`.")),
+    }
+}
+
+fn strip_comment(s: &str) -> &str {
+    s.split_once('#').map_or(s, |(value, _)| value).trim()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::Config;
+    use crate::year;
+
+    #[test]
+    fn parses_config() {
+        let config: Config = r#"
+# project settings
+year = 2024
+release = true # build optimized
+data_dir = "puzzles/data"
+
+[readme]
+path = "docs/README.md"
+marker = "<!-- \"bench\" # table -->"
+
+[bench]
+min_samples = 5
+max_samples = 1_000
+"#
+        .parse()
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                year: Some(year!(2024)),
+                release: true,
+                data_dir: PathBuf::from("puzzles/data"),
+                readme_path: PathBuf::from("docs/README.md"),
+                readme_marker: "<!-- \"bench\" # table -->".into(),
+                bench_min_samples: 5,
+                bench_max_samples: 1000,
+            }
+        );
+        assert_eq!(
+            config.year_dir(year!(2024)),
+            PathBuf::from("puzzles/data/2024")
+        );
+    }
+
+    #[test]
+    fn defaults_missing_settings() {
+        assert_eq!("".parse::<Config>(), Ok(Config::default()));
+        assert_eq!(
+            "[bench]\nmax_samples = 50".parse::<Config>().unwrap(),
+            Config {
+                bench_max_samples: 50,
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        assert!("yaer = 2025".parse::<Config>().is_err());
+        assert!("year = 2014".parse::<Config>().is_err());
+        assert!("year = \"2025\"".parse::<Config>().is_err());
+        assert!("release = yes".parse::<Config>().is_err());
+        assert!("data_dir = \"data".parse::<Config>().is_err());
+        assert!("[bench\nmin_samples = 10".parse::<Config>().is_err());
+        assert!("[bench]\nmin_samples = 0".parse::<Config>().is_err());
+        assert!(
+            "[bench]\nmin_samples = 100\nmax_samples = 10"
+                .parse::<Config>()
+                .is_err()
+        );
+        assert!("release = true\nrelease = false".parse::<Config>().is_err());
+    }
+}
@@ -5,10 +5,13 @@ use std::str::FromStr;
 #[cfg(feature = "today")]
 use chrono::{Datelike, FixedOffset, Utc};
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 12).
+/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Whether a day is part of the calendar of a given year is checked with [`Year::has_day`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -25,7 +28,7 @@ impl Day {
     /// Creates a [`Day`] from the provided value if it's in the valid range,
     /// returns [`None`] otherwise.
     pub const fn new(day: u8) -> Option<Self> {
-        if day == 0 || day > 12 {
+        if day == 0 || day > 25 {
             return None;
         }
         Some(Self(day))
@@ -39,12 +42,13 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 12th of december, `None` otherwise.
+    /// Returns the current day if it's part of this year's calendar in december, `None` otherwise.
     pub fn today() -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
         let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 12 {
-            Self::new(u8::try_from(today.day()).ok()?)
+        let day = Self::new(u8::try_from(today.day()).ok()?)?;
+        if today.month() == 12 && Year::today()?.has_day(day) {
+            Some(day)
         } else {
             None
         }
@@ -88,26 +92,29 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 12")
+        f.write_str("expecting a day number between 1 and 25")
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 12th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day in the calendar of a year, from the 1st to the 12th or 25th.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 12th.
+/// An iterator that yields every day in the calendar of a year, from the 1st to the 12th or 25th.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.days(),
+        }
     }
 }
 
@@ -115,10 +122,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 12 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 12.
+        // NOTE: the iterator starts at 1 and calendars are not longer than 25 days.
         let day = Day(self.current);
         self.current += 1;
 
@@ -134,7 +141,7 @@ macro_rules! day {
     ($day:expr) => {
         const {
             $crate::template::Day::new($day)
-                .expect("invalid day number, expecting a value between 1 and 12")
+                .expect("invalid day number, expecting a value between 1 and 25")
         }
     };
 }
@@ -144,10 +151,11 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{Day, all_days};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2025));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -163,6 +171,13 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(12)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_iterator_for_25_day_calendars() {
+        let days: Vec<Day> = all_days(year!(2024)).collect();
+        assert_eq!(days.len(), 25);
+        assert_eq!(days.last(), Some(&Day(25)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
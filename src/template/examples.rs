@@ -0,0 +1,235 @@
+//! Extraction of example inputs and expected answers from downloaded puzzle descriptions.
+//!
+//! Puzzles mark up their example input as a code block and the expected answer of the example as
+//! emphasized code, e.g. `` `*143*` ``. Both aoc-cli and the native client write them this way.
+
+/// The example of a single part of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    /// The first code block in the description of the part, if any.
+    pub input: Option<String>,
+    /// The last emphasized code span in the description of the part, if any.
+    pub answer: Option<String>,
+}
+
+/// Find the examples of both parts in the markdown of a puzzle.
+/// Part two is only part of the description once part one was solved.
+pub fn extract(markdown: &str) -> Vec<Example> {
+    let markdown = markdown.replace("\r\n", "\n");
+
+    let (part_one, part_two) = match markdown.find("--- Part Two ---") {
+        Some(index) => {
+            let start = markdown[..index].rfind('\n').map_or(0, |i| i + 1);
+            (&markdown[..start], Some(&markdown[start..]))
+        }
+        None => (markdown.as_str(), None),
+    };
+
+    [Some(part_one), part_two]
+        .into_iter()
+        .flatten()
+        .zip(1..)
+        .map(|(description, part)| {
+            // NOTE: the answer to the actual puzzle is emphasized as well once the part is solved.
+            let description = description
+                .find("Your puzzle answer was")
+                .map_or(description, |end| &description[..end]);
+
+            Example {
+                part,
+                input: code_blocks(description).into_iter().next(),
+                answer: emphasized_code(description).pop(),
+            }
+        })
+        .collect()
+}
+
+/// The contents of all fenced code blocks.
+fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match current.take() {
+                Some(lines) => blocks.push(lines.join("\n") + "\n"),
+                None => current = Some(Vec::new()),
+            }
+        } else if let Some(lines) = current.as_mut() {
+            lines.push(line);
+        }
+    }
+
+    blocks
+}
+
+/// The contents of all inline code spans that are emphasized, i.e. `` `*x*` `` or ``*`x`*``.
+fn emphasized_code(markdown: &str) -> Vec<String> {
+    let mut spans = Vec::new();
+    let mut rest = markdown;
+
+    while let Some(start) = rest.find('`') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('`') else {
+            break;
+        };
+
+        let code = &after[..end];
+        let is_emphasized = (code.len() > 2 && code.starts_with('*') && code.ends_with('*'))
+            || (rest[..start].ends_with('*') && after[end + 1..].starts_with('*'));
+
+        if is_emphasized {
+            let value = code.trim_matches('*').trim();
+            if !value.is_empty() {
+                spans.push(value.to_string());
+            }
+        }
+
+        rest = &after[end + 1..];
+    }
+
+    spans
+}
+
+/// Fill the expected answer into the test of a part in the source of a solution, and switch the
+/// test to its own example file if the parts have different examples.
+///
+/// Tests are only changed while they still assert `None`, as generated by `cargo scaffold`.
+/// Returns `None` if the test could not be found or was already filled.
+pub fn fill_test(source: &str, part: u8, answer: &str, has_part_example: bool) -> Option<String> {
+    let name = match part {
+        1 => "fn test_part_one()",
+        2 => "fn test_part_two()",
+        _ => return None,
+    };
+
+    let start = source.find(name)?;
+    let end = source[start + name.len()..]
+        .find("fn ")
+        .map_or(source.len(), |i| start + name.len() + i);
+    let body = &source[start..end];
+
+    let assertion = "assert_eq!(result, None);";
+    if !body.contains(assertion) {
+        return None;
+    }
+
+    let expected = if answer.parse::<i128>().is_ok() {
+        format!("assert_eq!(result, Some({answer}));")
+    } else {
+        format!("assert_eq!(result, Some({answer:?}.to_string()));")
+    };
+
+    let mut body = body.replacen(assertion, &expected, 1);
+
+    if has_part_example {
+        body = body.replacen(
+            "read_file(\"examples\", YEAR, DAY)",
+            &format!("read_file_part(\"examples\", YEAR, DAY, {part})"),
+            1,
+        );
+    }
+
+    Some(format!("{}{body}{}", &source[..start], &source[end..]))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, extract, fill_test};
+
+    const PUZZLE: &str = "## --- Day 1: Test ---
+
+Here is an example:
+
+```
+1 2
+3 4
+```
+
+Summing up *all* pairs, e.g. `1 + 2`, gives `*10*`.
+
+## --- Part Two ---
+
+Now, consider another example:
+
+```
+5 6
+7 8
+```
+
+The product is *`1680`*.
+
+Your puzzle answer was `*1234*`.
+";
+
+    const SOURCE: &str = r#"advent_of_code::solution!(1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+    #[test]
+    fn extracts_examples_per_part() {
+        assert_eq!(
+            extract(PUZZLE),
+            vec![
+                Example {
+                    part: 1,
+                    input: Some("1 2\n3 4\n".into()),
+                    answer: Some("10".into()),
+                },
+                Example {
+                    part: 2,
+                    input: Some("5 6\n7 8\n".into()),
+                    answer: Some("1680".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_examples_of_unsolved_puzzles() {
+        let part_one = PUZZLE.split("## --- Part Two").next().unwrap();
+        let examples = extract(part_one);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].answer.as_deref(), Some("10"));
+    }
+
+    #[test]
+    fn handles_missing_examples() {
+        let examples = extract("The answer for `(())` is `*0*`.\n");
+        assert_eq!(examples[0].input, None);
+        assert_eq!(examples[0].answer.as_deref(), Some("0"));
+    }
+
+    #[test]
+    fn fills_tests() {
+        let source = fill_test(SOURCE, 1, "10", false).unwrap();
+        assert!(source.contains("assert_eq!(result, Some(10));"));
+        assert!(source.contains("assert_eq!(result, None);"));
+
+        let source = fill_test(&source, 2, "abc", true).unwrap();
+        assert!(source.contains("assert_eq!(result, Some(\"abc\".to_string()));"));
+        assert!(source.contains("read_file_part(\"examples\", YEAR, DAY, 2)"));
+        assert!(source.contains("read_file(\"examples\", YEAR, DAY)"));
+
+        assert_eq!(fill_test(&source, 1, "11", false), None);
+    }
+}
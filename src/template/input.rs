@@ -0,0 +1,299 @@
+/// Selection of the input a solution runs against.
+///
+/// By default, solutions run against the official input in `data/<year>/inputs`. The arguments
+/// `--input <path>`, `--input -` (stdin) and `--example` passed to `cargo solve` select another one.
+/// Examples come with the parameters declared next to them, see [`Params`].
+use std::{env, fs, io, path::PathBuf, process};
+
+use crate::template::params::Params;
+use crate::template::{Day, Year, config};
+
+/// Where the input of a solution is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// The official puzzle input, e.g. `data/2025/inputs/01.txt`.
+    Official,
+    /// The example input, e.g. `data/2025/examples/01.txt`. Part files like `01-2.txt` take precedence.
+    Example,
+    /// An arbitrary file.
+    File(PathBuf),
+    /// Standard input.
+    Stdin,
+}
+
+impl Source {
+    /// Parse the source from the arguments passed to the solution binary.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let input = match args.iter().position(|x| x == "--input") {
+            Some(index) => match args.get(index + 1).map(String::as_str) {
+                Some("-") => Some(Source::Stdin),
+                Some(path) => Some(Source::File(PathBuf::from(path))),
+                None => return Err("`--input` expects a path, or `-` for stdin.".into()),
+            },
+            None => None,
+        };
+
+        match (input, args.iter().any(|x| x == "--example")) {
+            (Some(_), true) => Err("`--input` and `--example` cannot be combined.".into()),
+            (Some(source), false) => Ok(source),
+            (None, true) => Ok(Source::Example),
+            (None, false) => Ok(Source::Official),
+        }
+    }
+
+    pub fn is_official(&self) -> bool {
+        *self == Source::Official
+    }
+}
+
+/// The input of a solution, read from its [`Source`], along with its parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    common: Option<InputFile>,
+    parts: [Option<InputFile>; 2],
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct InputFile {
+    contents: String,
+    params: Params,
+}
+
+impl Input {
+    /// Read the input selected by the arguments passed to the solution binary for the given parts.
+    /// Exits the process if the arguments are invalid or the input cannot be read.
+    pub fn from_args(year: Year, day: Day, parts: &[u8]) -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let (source, overrides) = Source::from_args(&args)
+            .and_then(|source| Ok((source, params_from_args(&args)?)))
+            .unwrap_or_else(|e| {
+                eprintln!("Unexpected command-line input: {e}");
+                process::exit(1);
+            });
+
+        let mut input = Self::read(&source, year, day, parts).unwrap_or_else(|e| {
+            eprintln!("Could not read input: {e}");
+            process::exit(1);
+        });
+
+        input.override_params(&overrides);
+        input
+    }
+
+    /// Read the input of the given parts. Fails if a part has no example file.
+    pub fn read(source: &Source, year: Year, day: Day, parts: &[u8]) -> io::Result<Self> {
+        let folder = config::get().year_dir(year);
+
+        match source {
+            Source::Official => Ok(Self::from(fs::read_to_string(
+                folder.join("inputs").join(format!("{day}.txt")),
+            )?)),
+            Source::File(path) => Ok(Self::from(fs::read_to_string(path)?)),
+            Source::Stdin => Ok(Self::from(io::read_to_string(io::stdin())?)),
+            Source::Example => {
+                let examples = folder.join("examples");
+
+                let read = |name: String| -> io::Result<Option<InputFile>> {
+                    let Ok(contents) = fs::read_to_string(examples.join(format!("{name}.txt")))
+                    else {
+                        return Ok(None);
+                    };
+                    let params = Params::read_from_file(&examples.join(format!("{name}.params")))
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                    Ok(Some(InputFile { contents, params }))
+                };
+
+                let input = Input {
+                    common: read(day.to_string())?,
+                    parts: [read(format!("{day}-1"))?, read(format!("{day}-2"))?],
+                };
+
+                if input.common.is_none() && input.parts.iter().all(Option::is_none) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!("no example file for day {day} in {}", examples.display()),
+                    ));
+                }
+
+                if let Some(part) = input.missing_part(parts) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::NotFound,
+                        format!(
+                            "no example file for part {part} of day {day}, expected {}",
+                            examples.join(format!("{day}-{part}.txt")).display()
+                        ),
+                    ));
+                }
+
+                Ok(input)
+            }
+        }
+    }
+
+    /// The input shared by all parts, or `None` if the parts have inputs of their own.
+    pub fn common(&self) -> Option<&str> {
+        if self.parts.iter().any(Option::is_some) {
+            return None;
+        }
+        self.common.as_ref().map(|x| x.contents.as_str())
+    }
+
+    /// The input of a single part.
+    pub fn part(&self, part: u8) -> &str {
+        &self.file(part).contents
+    }
+
+    /// The parameters of a single part.
+    pub fn params(&self, part: u8) -> &Params {
+        &self.file(part).params
+    }
+
+    /// Set parameters on all parts, replacing the ones declared next to the input.
+    pub fn override_params(&mut self, params: &Params) {
+        for file in self.parts.iter_mut().chain([&mut self.common]).flatten() {
+            file.params.extend(params);
+        }
+    }
+
+    /// The first of the parts that has neither an input of its own nor a common one.
+    fn missing_part(&self, parts: &[u8]) -> Option<u8> {
+        parts
+            .iter()
+            .copied()
+            .find(|&part| self.find_file(part).is_none())
+    }
+
+    fn find_file(&self, part: u8) -> Option<&InputFile> {
+        self.parts
+            .get(usize::from(part) - 1)
+            .and_then(Option::as_ref)
+            .or(self.common.as_ref())
+    }
+
+    fn file(&self, part: u8) -> &InputFile {
+        self.find_file(part).expect("could not open input file")
+    }
+}
+
+impl From<String> for Input {
+    fn from(value: String) -> Self {
+        Input {
+            common: Some(InputFile {
+                contents: value,
+                params: Params::default(),
+            }),
+            parts: [None, None],
+        }
+    }
+}
+
+/// Parse the parameters passed to the solution binary as `--param key=value`.
+pub fn params_from_args(args: &[String]) -> Result<Params, String> {
+    let mut params = Params::default();
+
+    for (index, _) in args.iter().enumerate().filter(|(_, x)| *x == "--param") {
+        let assignment = args
+            .get(index + 1)
+            .ok_or("`--param` expects `key=value`.")?;
+        let (key, value) = Params::parse_assignment(assignment)?;
+        params.set(&key, &value);
+    }
+
+    Ok(params)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{Input, InputFile, Source, params_from_args};
+    use crate::template::params::Params;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parses_sources() {
+        assert_eq!(
+            Source::from_args(&args(&["01", "--release"])),
+            Ok(Source::Official)
+        );
+        assert_eq!(
+            Source::from_args(&args(&["01", "--example"])),
+            Ok(Source::Example)
+        );
+        assert_eq!(
+            Source::from_args(&args(&["01", "--input", "-"])),
+            Ok(Source::Stdin)
+        );
+        assert_eq!(
+            Source::from_args(&args(&["01", "--input", "edge.txt", "--submit", "1"])),
+            Ok(Source::File(PathBuf::from("edge.txt")))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_sources() {
+        assert!(Source::from_args(&args(&["01", "--input"])).is_err());
+        assert!(Source::from_args(&args(&["01", "--input", "-", "--example"])).is_err());
+    }
+
+    #[test]
+    fn selects_part_inputs() {
+        let file = |contents: &str, params: &str| InputFile {
+            contents: contents.into(),
+            params: params.parse().unwrap(),
+        };
+        let mut input = Input {
+            common: Some(file("common", "steps = 10")),
+            parts: [None, Some(file("second", "steps = 20"))],
+        };
+        assert_eq!(input.common(), None);
+        assert_eq!(input.part(1), "common");
+        assert_eq!(input.part(2), "second");
+        assert_eq!(input.params(1).get::<u32>("steps"), Some(10));
+        assert_eq!(input.params(2).get::<u32>("steps"), Some(20));
+
+        input.override_params(&"steps = 5".parse().unwrap());
+        assert_eq!(input.params(1).get::<u32>("steps"), Some(5));
+        assert_eq!(input.params(2).get::<u32>("steps"), Some(5));
+
+        let input = Input::from("common".to_string());
+        assert_eq!(input.common(), Some("common"));
+        assert_eq!(input.part(2), "common");
+        assert_eq!(input.params(2), &Params::default());
+    }
+
+    #[test]
+    fn finds_missing_parts() {
+        let file = |contents: &str| InputFile {
+            contents: contents.into(),
+            params: Params::default(),
+        };
+        let input = Input {
+            common: None,
+            parts: [None, Some(file("second"))],
+        };
+        assert_eq!(input.missing_part(&[2]), None);
+        assert_eq!(input.missing_part(&[1, 2]), Some(1));
+
+        let input = Input {
+            common: Some(file("common")),
+            parts: [None, None],
+        };
+        assert_eq!(input.missing_part(&[1, 2]), None);
+    }
+
+    #[test]
+    fn parses_param_overrides() {
+        let params =
+            params_from_args(&args(&["01", "--param", "a=1", "--param", "b = 2"])).unwrap();
+        assert_eq!(params.to_string(), "a=1, b=2");
+        assert!(params_from_args(&args(&["01", "--param"])).is_err());
+        assert!(params_from_args(&args(&["01", "--param", "a"])).is_err());
+    }
+}
@@ -1,68 +1,206 @@
 use std::{env, fs};
 
+pub mod answers;
 pub mod aoc_cli;
+#[cfg(feature = "native-client")]
+pub mod aoc_client;
 pub mod commands;
+pub mod config;
+pub mod examples;
+pub mod input;
+pub mod params;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod submissions;
+pub mod timings_history;
 
 pub use day::*;
+pub use year::*;
 
 mod day;
 mod readme_benchmarks;
 mod run_multi;
+#[cfg(feature = "test_lib")]
+pub(crate) mod test_support;
 mod timings;
+mod watch;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a year to a string. E.g. like `data/2025/inputs/01.txt`.
+/// The data folder is configured in `aoc.toml`, see [`config::Config`].
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(config::get().year_dir(year))
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
+        .join(config::get().year_dir(year))
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads the parameters declared next to a file of a year. E.g. like `data/2025/examples/08.params`.
+/// Returns no parameters if the file does not exist.
+#[must_use]
+pub fn read_params(folder: &str, year: Year, day: Day) -> params::Params {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join(config::get().year_dir(year))
+        .join(folder)
+        .join(format!("{day}.params"));
+    params::Params::read_from_file(&filepath).expect("could not parse parameters file")
+}
+
+/// Helper function that reads the parameters declared next to a part file. E.g. like `08-2.params`.
+#[must_use]
+pub fn read_params_part(folder: &str, year: Year, day: Day, part: u8) -> params::Params {
+    let cwd = env::current_dir().unwrap();
+    let filepath = cwd
+        .join(config::get().year_dir(year))
+        .join(folder)
+        .join(format!("{day}-{part}.params"));
+    params::Params::read_from_file(&filepath).expect("could not parse parameters file")
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+///
+/// The year is taken from the name of the solution file, e.g. `src/bin/2025-01.rs`.
+///
+/// Parts return either `Option<T>` or `Result<T, E>`, see [`runner::PartOutput`].
+///
+/// The optional parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The optional `parser: <fn>` parameter parses the input once, timed on its own, and hands
+/// a reference to the parsed value to both parts, e.g. `solution!(4, parser: parse)`.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional `params` flag passes the [`params::Params`] of the input to both parts as second
+/// argument, e.g. `solution!(8, parser: parse, params)`.
+///
+/// The solution runs against the official input, unless another one is selected with
+/// `--input <path>`, `--input -` or `--example`. See [`input::Source`].
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl plain $day, [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl plain $day, [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl plain $day, [part_two, 2]);
+    };
+    ($day:expr, params) => {
+        $crate::solution!(@impl params $day, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, params, 1) => {
+        $crate::solution!(@impl params $day, [part_one, 1]);
+    };
+    ($day:expr, params, 2) => {
+        $crate::solution!(@impl params $day, [part_two, 2]);
+    };
+    ($day:expr, parser: $parser:expr) => {
+        $crate::solution!(@impl plain $day, $parser, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, parser: $parser:expr, 1) => {
+        $crate::solution!(@impl plain $day, $parser, [part_one, 1]);
+    };
+    ($day:expr, parser: $parser:expr, 2) => {
+        $crate::solution!(@impl plain $day, $parser, [part_two, 2]);
+    };
+    ($day:expr, parser: $parser:expr, params) => {
+        $crate::solution!(@impl params $day, $parser, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, parser: $parser:expr, params, 1) => {
+        $crate::solution!(@impl params $day, $parser, [part_one, 1]);
+    };
+    ($day:expr, parser: $parser:expr, params, 2) => {
+        $crate::solution!(@impl params $day, $parser, [part_two, 2]);
+    };
+
+    (@impl $mode:ident $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@day $day, $( [$part, |input| {
+            let params = $crate::template::params::Params::default();
+            let output = $crate::solution!(@call $mode $func, input, &params);
+            $crate::template::runner::PartOutput::into_answer(output).map(|x| x.map(|x| x.to_string()))
+        }] )*);
+
+        fn run_input(input: &$crate::template::input::Input) {
+            use $crate::template::runner::*;
+            $( run_part(|x| $crate::solution!(@call $mode $func, x, input.params($part)), input.part($part), YEAR, DAY, $part); )*
+        }
+    };
+    (@impl $mode:ident $day:expr, $parser:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@day $day, $( [$part, |input| {
+            let params = $crate::template::params::Params::default();
+            let output = $crate::solution!(@call $mode $func, &$parser(input), &params);
+            $crate::template::runner::PartOutput::into_answer(output).map(|x| x.map(|x| x.to_string()))
+        }] )*);
+
+        fn run_input(input: &$crate::template::input::Input) {
+            use $crate::template::runner::*;
+            match input.common() {
+                Some(common) => {
+                    let parsed = run_parse($parser, common, DAY);
+                    $( run_part(|x| $crate::solution!(@call $mode $func, x, input.params($part)), &parsed, YEAR, DAY, $part); )*
+                }
+                None => {
+                    $( run_part(|x| $crate::solution!(@call $mode $func, x, input.params($part)), &run_parse($parser, input.part($part), DAY), YEAR, DAY, $part); )*
+                }
+            }
+        }
+    };
+
+    (@call plain $func:expr, $input:expr, $params:expr) => {
+        $func($input)
+    };
+    (@call params $func:expr, $input:expr, $params:expr) => {
+        $func($input, $params)
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@day $day:expr, $( [$part:expr, $solve:expr] )*) => {
+        /// The current year, read from the name of the solution file.
+        const YEAR: $crate::template::Year = $crate::template::Year::from_solution_path(file!());
+
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// The current day's entry in the solution registry of the `all` binary.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution = $crate::template::registry::Solution {
+            year: YEAR,
+            day: DAY,
+            parts: &[ $( $crate::template::registry::Part { part: $part, solve: $solve } ),* ],
+            run: run_solution,
+        };
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        fn run_solution(input: &str) {
+            run_input(&$crate::template::input::Input::from(input.to_string()));
+        }
+
+        #[allow(dead_code)]
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            run_input(&$crate::template::input::Input::from_args(YEAR, DAY, &[ $( $part ),* ]));
         }
     };
 }
@@ -0,0 +1,139 @@
+/// Parameters of a solution that differ between inputs, e.g. the number of steps to simulate,
+/// which is often smaller for the example than for the real input.
+///
+/// Parameters of an example are declared next to it, e.g. `data/2025/examples/08.params` for
+/// `08.txt` and `08-2.params` for `08-2.txt`, with one `key = value` pair per line.
+/// `cargo solve` overrides them with `--param key=value`.
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path, str::FromStr};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// Read parameters from a file. If not present, returns no parameters.
+    pub fn read_from_file(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .parse()
+                .map_err(|e| format!("{}: {e}", path.display())),
+            Err(_) => Ok(Params::default()),
+        }
+    }
+
+    /// Read a parameter, or `None` if it is not set.
+    /// Panics if the value can not be parsed, as the solution can not run with it.
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        let value = self.values.get(key)?;
+        match value.parse() {
+            Ok(value) => Some(value),
+            Err(_) => panic!("invalid value `{value}` for parameter `{key}`"),
+        }
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) {
+        self.values.insert(key.to_string(), value.to_string());
+    }
+
+    /// Set the parameters of the other set, replacing the ones already set.
+    pub fn extend(&mut self, other: &Params) {
+        self.values
+            .extend(other.values.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Parse a single `key=value` assignment as passed to `--param`.
+    pub fn parse_assignment(assignment: &str) -> Result<(String, String), String> {
+        let (key, value) = assignment
+            .split_once('=')
+            .ok_or(format!("expected `key=value`, got `{assignment}`."))?;
+
+        let (key, value) = (key.trim(), value.trim());
+        if key.is_empty() {
+            return Err(format!("missing key in `{assignment}`."));
+        }
+
+        Ok((key.to_string(), value.to_string()))
+    }
+}
+
+impl FromStr for Params {
+    type Err = String;
+
+    /// Parse `key = value` lines. Empty lines and lines starting with `#` are skipped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut params = Params::default();
+
+        for (index, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) =
+                Params::parse_assignment(line).map_err(|e| format!("line {}: {e}", index + 1))?;
+            params.values.insert(key, value);
+        }
+
+        Ok(params)
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pairs: Vec<String> = self
+            .values
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect();
+        f.write_str(&pairs.join(", "))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Params;
+
+    #[test]
+    fn parses_params() {
+        let params: Params = "# example\nnum_connections = 10\n\nname=abc\n"
+            .parse()
+            .unwrap();
+        assert_eq!(params.get::<usize>("num_connections"), Some(10));
+        assert_eq!(params.get::<String>("name"), Some("abc".into()));
+        assert_eq!(params.get::<usize>("missing"), None);
+        assert_eq!(params.to_string(), "name=abc, num_connections=10");
+    }
+
+    #[test]
+    fn rejects_malformed_params() {
+        assert!("num_connections".parse::<Params>().is_err());
+        assert!("= 10".parse::<Params>().is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_values() {
+        let params: Params = "num_connections = ten".parse().unwrap();
+        params.get::<usize>("num_connections");
+    }
+
+    #[test]
+    fn overrides_params() {
+        let mut params: Params = "a = 1\nb = 2".parse().unwrap();
+        let (key, value) = Params::parse_assignment("b=3").unwrap();
+        params.set(&key, &value);
+        params.extend(&"c = 4".parse().unwrap());
+        assert_eq!(params.to_string(), "a=1, b=3, c=4");
+    }
+}
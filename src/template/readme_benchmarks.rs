@@ -2,10 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
-
-static MARKER: &str = "<!--- benchmarking table --->";
+use crate::template::timings::{StepTiming, Timings};
+use crate::template::{Day, Year, config};
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -26,101 +24,181 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}-{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// The marker of the table of a year, e.g. `<!--- benchmarking table 2025 --->` for the
+/// configured marker `<!--- benchmarking table --->`.
+fn year_marker(marker: &str, year: Year) -> String {
+    match marker.rfind(" --") {
+        Some(index) if marker.ends_with("->") => {
+            format!("{} {year}{}", &marker[..index], &marker[index..])
+        }
+        _ => format!("{marker} {year}"),
+    }
+}
 
-    if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+/// Locate the table enclosed by a pair of the marker. Returns `None` if the marker does not occur.
+fn locate_table(readme: &str, marker: &str) -> Result<Option<TablePosition>, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
+
+    match matches.as_slice() {
+        [] => Ok(None),
+        [start, end] => Ok(Some(TablePosition {
+            pos_start: start.0,
+            pos_end: end.0 + end.1.len(),
+        })),
+        [_] => Err(Error::Parser(format!(
+            "{marker}: could not find table end position."
+        ))),
+        _ => Err(Error::Parser(format!(
+            "{marker}: too many occurences of marker in README."
+        ))),
     }
+}
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+/// The end of the last benchmark table in the readme, of any year or enclosed by the plain
+/// marker of older readmes. New years are inserted there.
+fn locate_last_table_end(readme: &str, marker: &str) -> Option<usize> {
+    let (prefix, suffix) = match marker.rfind(" --") {
+        Some(index) if marker.ends_with("->") => marker.split_at(index),
+        _ => (marker, ""),
+    };
 
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+    let year_ends = readme.match_indices(prefix).filter_map(|(index, _)| {
+        let rest = readme[index + prefix.len()..].strip_prefix(' ')?;
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        (digits > 0 && rest[digits..].starts_with(suffix))
+            .then_some(readme.len() - rest.len() + digits + suffix.len())
+    });
+    let plain_ends = readme
+        .match_indices(marker)
+        .map(|(index, _)| index + marker.len());
 
-    Ok(TablePosition { pos_start, pos_end })
+    year_ends.chain(plain_ends).max()
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn format_step(step: Option<&StepTiming>) -> String {
+    step.map_or_else(|| "-".into(), |step| format!("{:.1?}", step.duration))
+}
+
+fn construct_table(
+    prefix: &str,
+    marker: &str,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> String {
+    let header = format!("{prefix} Benchmarks {year}");
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Parse | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---:  |".into(),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(year, timing.day);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{}` | `{}` | `{}` |",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            format_step(timing.parse.as_ref()),
+            format_step(timing.part_1.as_ref()),
+            format_step(timing.part_2.as_ref())
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+/// Write the table of the year, replacing only the table of that year. The table of a year that is
+/// stored for the first time is added after the last existing table.
+fn update_content(
+    s: &mut String,
+    marker: &str,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+) -> Result<(), Error> {
+    let marker_of_year = year_marker(marker, year);
+    let table = construct_table("##", &marker_of_year, year, timings, total_millis);
+
+    match locate_table(s, &marker_of_year)? {
+        Some(positions) => s.replace_range(positions.pos_start..positions.pos_end, &table),
+        None => {
+            let end = locate_last_table_end(s, marker).ok_or_else(|| {
+                Error::Parser(format!("{marker}: could not find table start position."))
+            })?;
+            s.insert_str(end, &format!("\n\n{table}"));
+        }
+    }
+
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
+    let config = config::get();
+    let mut readme = String::from_utf8_lossy(&fs::read(&config.readme_path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+    update_content(
+        &mut readme,
+        &config.readme_marker,
+        year,
+        timings,
+        total_millis,
+    )?;
+    fs::write(&config.readme_path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use std::time::Duration;
+
+    use super::{update_content, year_marker};
+    use crate::template::timings::{StepTiming, Timing, Timings};
+    use crate::{day, year};
+
+    const MARKER: &str = "<!--- benchmarking table --->";
+    const MARKER_2025: &str = "<!--- benchmarking table 2025 --->";
+
+    fn step(duration: Duration) -> Option<StepTiming> {
+        Some(StepTiming {
+            duration,
+            stats: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: step(Duration::from_micros(5)),
+                    part_1: step(Duration::from_millis(10)),
+                    part_2: step(Duration::from_millis(20)),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: step(Duration::from_millis(30)),
+                    part_2: step(Duration::from_millis(40)),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
+                    parse: None,
+                    part_1: step(Duration::from_millis(40)),
+                    part_2: step(Duration::from_nanos(74_130)),
                     total_nanos: 9e+10,
                 },
             ],
@@ -131,50 +209,82 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, year!(2025), get_mock_timings(), 190.0).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("{} {} {}", MARKER_2025, MARKER_2025, MARKER_2025);
+        update_content(&mut s, MARKER, year!(2025), get_mock_timings(), 190.0).unwrap();
+    }
+
+    #[test]
+    fn derives_year_markers() {
+        assert_eq!(year_marker(MARKER, year!(2025)), MARKER_2025);
+        assert_eq!(
+            year_marker("<!-- bench -->", year!(2024)),
+            "<!-- bench 2024 -->"
+        );
+        assert_eq!(year_marker("[bench]", year!(2024)), "[bench] 2024");
     }
 
     #[test]
-    fn updates_empty_benchmarks() {
+    fn adds_table_after_plain_markers() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, MARKER, year!(2025), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches(MARKER_2025).count(), 2);
+        assert_eq!(s.contains("## Benchmarks 2025"), true);
+        assert_eq!(s.ends_with(&format!("{}\nbaz", MARKER_2025)), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER_2025, MARKER_2025);
+        update_content(&mut s, MARKER, year!(2025), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, year!(2025), get_mock_timings(), 190.0).unwrap();
+        assert_eq!(s.matches(MARKER_2025).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn keeps_tables_of_other_years() {
+        let mut s = format!("foo\n{}{}\nbaz", MARKER_2025, MARKER_2025);
+        update_content(&mut s, MARKER, year!(2025), get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, year!(2024), get_mock_timings(), 150.0).unwrap();
+        update_content(&mut s, MARKER, year!(2025), get_mock_timings(), 120.0).unwrap();
+
+        let table_2025 = s.find("## Benchmarks 2025").unwrap();
+        let table_2024 = s.find("## Benchmarks 2024").unwrap();
+        assert!(table_2025 < table_2024);
+        assert_eq!(s.matches("<!--- benchmarking table 2024 --->").count(), 2);
+        assert_eq!(s.matches(MARKER_2025).count(), 2);
+        assert_eq!(s.contains("./src/bin/2024-01.rs"), true);
+        assert_eq!(s.contains("**Total: 150.00ms**"), true);
+        assert_eq!(s.contains("**Total: 120.00ms**"), true);
+        assert_eq!(s.contains("**Total: 190.00ms**"), false);
+        assert_eq!(s.ends_with("--->\nbaz"), true);
+    }
+
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER_2025, MARKER_2025);
+        update_content(&mut s, MARKER, year!(2025), get_mock_timings(), 190.0).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2025 --->",
+            "## Benchmarks 2025",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2025-01.rs) | `5.0µs` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2025-02.rs) | `-` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2025-04.rs) | `-` | `40.0ms` | `74.1µs` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2025 --->",
             "baz",
         ]
         .join("\n");
@@ -0,0 +1,166 @@
+/// In-process registry of solutions.
+/// The `solution!` macro exposes every day as a [`Solution`], which the `all` binary uses to run many days in one process.
+use std::{
+    collections::BTreeMap,
+    env,
+    io::{Write, stderr, stdout},
+    panic,
+    process::{self, Command, Output},
+    sync::{Mutex, mpsc},
+    thread,
+};
+
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, Year, all_days, read_file};
+
+/// A single part of a solution, with its answer or error converted to a string.
+pub struct Part {
+    pub part: u8,
+    pub solve: fn(&str) -> Result<Option<String>, String>,
+}
+
+/// The parts of a day, plus a runner that prints, times and reports them like the day binary does.
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    pub parts: &'static [Part],
+    pub run: fn(&str),
+}
+
+/// Run the days of the year selected via `--year` (the configured year if not passed) and `--days 01,02`
+/// (every day of the calendar if not passed) against their inputs.
+/// A panic in one day is reported and does not stop the remaining days.
+///
+/// With `--jobs <n>`, up to `n` days run concurrently in child processes. Their output is buffered and
+/// printed as one block per day, in day order.
+pub fn run(solutions: &[Solution]) {
+    let (year, days, jobs) = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        }
+    };
+
+    let days: Vec<Day> = all_days(year)
+        .filter(|day| days.as_ref().is_none_or(|days| days.contains(day)))
+        .collect();
+
+    if jobs > 1 && days.len() > 1 {
+        run_parallel(&days, jobs);
+        return;
+    }
+
+    let mut need_space = false;
+
+    for day in days {
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+
+        let Some(solution) = solutions.iter().find(|s| s.year == year && s.day == day) else {
+            println!("Not solved.");
+            continue;
+        };
+
+        // NOTE: the panic hook already printed the message to stderr.
+        let _ = panic::catch_unwind(|| (solution.run)(&read_file("inputs", year, day)));
+    }
+}
+
+/// Re-invoke the current binary for every day, keeping at most `jobs` children alive at a time.
+fn run_parallel(days: &[Day], jobs: usize) {
+    let exe = env::current_exe().expect("could not locate the current executable");
+    let args = get_forwarded_args();
+
+    let queue = Mutex::new(days.iter().copied().enumerate());
+    let (tx, rx) = mpsc::channel::<(usize, Day, std::io::Result<Output>)>();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let (tx, queue, exe, args) = (tx.clone(), &queue, &exe, &args);
+            scope.spawn(move || {
+                loop {
+                    let Some((index, day)) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    let output = Command::new(exe)
+                        .args(args)
+                        .args(["--days", &day.to_string()])
+                        .output();
+                    tx.send((index, day, output)).unwrap();
+                }
+            });
+        }
+        drop(tx);
+
+        // print finished days in order, holding back days that finished early.
+        let mut pending = BTreeMap::new();
+        let mut next_index = 0;
+
+        for (index, day, output) in rx {
+            pending.insert(index, (day, output));
+
+            while let Some((day, output)) = pending.remove(&next_index) {
+                if next_index > 0 {
+                    println!();
+                }
+                print_block(day, output);
+                next_index += 1;
+            }
+        }
+    });
+}
+
+fn print_block(day: Day, output: std::io::Result<Output>) {
+    match output {
+        Ok(output) => {
+            let _ = stdout().write_all(&output.stdout);
+            let _ = stdout().flush();
+            let _ = stderr().write_all(&output.stderr);
+        }
+        Err(e) => {
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+            println!("------");
+            eprintln!("Failed to run day {day}: {e}");
+        }
+    }
+}
+
+fn parse_args() -> Result<(Year, Option<Vec<Day>>, usize), pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let year = args
+        .opt_value_from_str("--year")?
+        .or_else(Year::from_config)
+        .ok_or(pico_args::Error::MissingOption(pico_args::Keys::from(
+            "--year",
+        )))?;
+    let days = args.opt_value_from_fn("--days", |s| {
+        s.split(',')
+            .map(str::parse)
+            .collect::<Result<Vec<Day>, _>>()
+    })?;
+    let jobs = args.opt_value_from_str("--jobs")?.unwrap_or(1);
+
+    Ok((year, days, jobs))
+}
+
+/// All arguments of the current invocation except for `--days` and `--jobs`, which are set per child.
+fn get_forwarded_args() -> Vec<String> {
+    let mut forwarded = vec![];
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        if arg == "--days" || arg == "--jobs" {
+            args.next();
+        } else {
+            forwarded.push(arg);
+        }
+    }
+
+    forwarded
+}
@@ -0,0 +1,301 @@
+/// Machine-readable records that solution binaries emit next to their human-readable output.
+///
+/// When a solution binary is called with `--report <path>`, it appends one JSON line per step
+/// (parse, part 1, part 2) to that file. `run_multi` reads these records instead of parsing display text.
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::runner::BenchStats;
+
+/// The step of a solution a record belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => f.write_str("parse"),
+            Step::Part(part) => write!(f, "part_{part}"),
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            "part_1" => Ok(Step::Part(1)),
+            "part_2" => Ok(Step::Part(2)),
+            _ => Err(format!("unknown step `{s}`.")),
+        }
+    }
+}
+
+/// The outcome of a single step of a solution run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: Day,
+    pub step: Step,
+    /// The answer of a part. Always `None` for the parse step and for unsolved parts.
+    pub answer: Option<String>,
+    /// Execution time. The mean when benched, the single run otherwise.
+    pub duration: Duration,
+    pub samples: u128,
+    /// Distribution of the samples, only present when benched.
+    pub stats: Option<BenchStats>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// Whether the record holds a benchmark of a parse step or of a solved part.
+    pub fn is_benched(&self) -> bool {
+        self.samples > 1
+            && self.error.is_none()
+            && (self.step == Step::Parse || self.answer.is_some())
+    }
+}
+
+/// Read the report path from the `--report <path>` argument, if passed.
+pub fn get_report_path() -> Option<PathBuf> {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == "--report")? + 1;
+    args.get(index).map(PathBuf::from)
+}
+
+/// Append a record to the report file as a single JSON line.
+pub fn append(path: &Path, record: &Record) -> io::Result<()> {
+    let line = JsonValue::from(record)
+        .stringify()
+        .map_err(|e| io::Error::other(e.to_string()))?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    // NOTE: a single write keeps lines intact when several processes append to the same report.
+    file.write_all(format!("{line}\n").as_bytes())
+}
+
+/// Read all records from a report file.
+pub fn read(path: &Path) -> Result<Vec<Record>, String> {
+    let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    parse_lines(&contents)
+}
+
+fn parse_lines(contents: &str) -> Result<Vec<Record>, String> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("not a valid JSON line."))?;
+            Record::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Insert the statistics of a benchmark as flat keys, durations in nanoseconds.
+/// The mean is not inserted, it is stored as `nanos` by both records and timings.
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn insert_stats(map: &mut HashMap<String, JsonValue>, stats: &BenchStats) {
+    let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+    map.insert("samples".into(), JsonValue::Number(stats.samples as f64));
+    map.insert("median".into(), nanos(stats.median));
+    map.insert("min".into(), nanos(stats.min));
+    map.insert("max".into(), nanos(stats.max));
+    map.insert("std_dev".into(), nanos(stats.std_dev));
+    map.insert("p95".into(), nanos(stats.p95));
+    map.insert("outliers".into(), JsonValue::Number(stats.outliers as f64));
+}
+
+/// Read statistics inserted by [`insert_stats`]. Returns `None` if they are not present.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn read_stats(
+    map: &HashMap<String, JsonValue>,
+    mean: Duration,
+) -> Result<Option<BenchStats>, String> {
+    if !map.contains_key("median") {
+        return Ok(None);
+    }
+
+    let number = |key: &str| {
+        map.get(key)
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or(format!("Expected stats.{key} to be a number."))
+    };
+    let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+    Ok(Some(BenchStats {
+        samples: number("samples")? as u128,
+        mean,
+        median: duration("median")?,
+        min: duration("min")?,
+        max: duration("max")?,
+        std_dev: duration("std_dev")?,
+        p95: duration("p95")?,
+        outliers: number("outliers")? as usize,
+    }))
+}
+
+fn optional_string(value: Option<&String>) -> JsonValue {
+    match value {
+        Some(x) => JsonValue::String(x.clone()),
+        None => JsonValue::Null,
+    }
+}
+
+impl From<&Record> for JsonValue {
+    fn from(value: &Record) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("step".into(), JsonValue::String(value.step.to_string()));
+        map.insert("answer".into(), optional_string(value.answer.as_ref()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("error".into(), optional_string(value.error.as_ref()));
+
+        if let Some(stats) = &value.stats {
+            insert_stats(&mut map, stats);
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Record {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        let step = json
+            .get("step")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.step to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.samples to be a number.")?;
+
+        let error = json
+            .get("error")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.error to be null or string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = Duration::from_nanos(nanos as u64);
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Record {
+            day,
+            step,
+            answer: answer.cloned(),
+            duration,
+            samples: samples as u128,
+            stats: read_stats(json, duration)?,
+            error: error.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Record, Step, parse_lines};
+    use crate::day;
+    use crate::template::runner::BenchStats;
+
+    #[test]
+    fn round_trips_records() {
+        let record = Record {
+            day: day!(3),
+            step: Step::Part(2),
+            answer: Some("line 1\nline (2 @ 5 samples)".into()),
+            duration: Duration::from_nanos(74_130),
+            samples: 100,
+            stats: Some(BenchStats {
+                samples: 100,
+                mean: Duration::from_nanos(74_130),
+                median: Duration::from_nanos(74_000),
+                min: Duration::from_nanos(70_000),
+                max: Duration::from_nanos(90_000),
+                std_dev: Duration::from_nanos(1_500),
+                p95: Duration::from_nanos(80_000),
+                outliers: 3,
+            }),
+            error: None,
+        };
+
+        let line = JsonValue::from(&record).stringify().unwrap();
+        assert!(!line.contains('\n'));
+
+        let records = parse_lines(&format!("{line}\n\n{line}\n")).unwrap();
+        assert_eq!(records, vec![record.clone(), record]);
+    }
+
+    #[test]
+    fn parses_parse_step() {
+        let records = parse_lines(
+            r#"{ "day": "01", "step": "parse", "answer": null, "nanos": 1500, "samples": 1, "error": null }"#,
+        )
+        .unwrap();
+        assert_eq!(records[0].step, Step::Parse);
+        assert_eq!(records[0].answer, None);
+        assert_eq!(records[0].duration, Duration::from_nanos(1500));
+        assert_eq!(records[0].stats, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_unknown_step() {
+        parse_lines(
+            r#"{ "day": "01", "step": "part_3", "answer": null, "nanos": 0, "samples": 1, "error": null }"#,
+        )
+        .unwrap();
+    }
+}
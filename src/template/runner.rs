@@ -1,63 +1,240 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::Any;
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::ocr;
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::input::{Source, params_from_args};
+use crate::template::report::{self, Record, Step};
+use crate::template::submissions::{Submissions, Verdict};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, Year, answers, config};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// The return value of a solution part: `Option<T>` for parts that may not be solved yet,
+/// `Result<T, E>` for parts that can fail, e.g. on malformed input.
+pub trait PartOutput {
+    type Answer: Display;
+
+    /// The answer of the part, `None` if it is not solved, or the error it failed with.
+    fn into_answer(self) -> Result<Option<Self::Answer>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        Ok(self)
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    type Answer = T;
+
+    fn into_answer(self) -> Result<Option<T>, String> {
+        self.map(Some).map_err(|e| e.to_string())
+    }
+}
+
+/// Run a solution part, print and report its answer. Errors and panics of the part are
+/// printed and reported as a failed part instead of aborting the run.
+pub fn run_part<I: Copy, O: PartOutput>(
+    func: impl Fn(I) -> O,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, cold_duration, stats) = run_timed(
+        |input| func(input).into_answer(),
+        input,
+        |result| {
+            print_result(result, &part_str, "");
+            result.is_ok()
+        },
+    );
+
+    let result = result
+        .map_err(|message| format!("panicked: {message}"))
+        .and_then(|result| result);
+
+    let duration_str = match &stats {
+        Some(stats) => format_duration(&stats.mean, stats.samples),
+        None => format_duration(&cold_duration, 1),
+    };
+
+    print_result(&result, &part_str, &duration_str);
+
+    if let Some(stats) = stats {
+        print_stats(&stats, &cold_duration);
+    }
+
+    let (answer, error) = match &result {
+        Ok(answer) => (answer.as_ref().map(|x| answer_text(x.to_string())), None),
+        Err(e) => (None, Some(e.clone())),
+    };
+
+    report_step(
+        day,
+        Step::Part(part),
+        answer,
+        error,
+        &cold_duration,
+        stats.as_ref(),
+    );
+
+    if let Ok(Some(result)) = result {
+        submit_result(result, year, day, part);
+    }
+}
+
+/// The text of an answer as it is reported: answers drawn as ASCII-art letters are decoded,
+/// all other answers are kept as they are.
+fn answer_text(answer: String) -> String {
+    if answer.contains('\n') {
+        ocr::decode(&answer).unwrap_or(answer)
+    } else {
+        answer
+    }
+}
+
+/// Run the parser of a solution once, print its timing and return the parsed input for the parts.
+/// A panic of the parser is reported before it is passed on, as the parts can not run without it.
+pub fn run_parse<T>(func: impl Fn(&str) -> T, input: &str, day: Day) -> T {
+    let (result, cold_duration, stats) = run_timed(func, input, |_| {
+        print!("Parse:");
+        true
+    });
+
+    let result = match result {
+        Ok(result) => result,
+        Err(message) => {
+            print!("\r");
+            println!("Parse: ✖ panicked: {message}");
+            report_step(
+                day,
+                Step::Parse,
+                None,
+                Some(format!("panicked: {message}")),
+                &cold_duration,
+                None,
+            );
+            // NOTE: the panic hook already printed the message, resuming does not print it again.
+            panic::resume_unwind(Box::new(message));
+        }
+    };
+
+    let duration_str = match &stats {
+        Some(stats) => format_duration(&stats.mean, stats.samples),
+        None => format_duration(&cold_duration, 1),
+    };
+
+    print!("\r");
+    println!("Parse:{duration_str}");
+
+    if let Some(stats) = stats {
+        print_stats(&stats, &cold_duration);
+    }
+
+    report_step(day, Step::Parse, None, None, &cold_duration, stats.as_ref());
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    result
+}
+
+/// Append a structured record for a step to the report file, if one was requested via `--report <path>`.
+fn report_step(
+    day: Day,
+    step: Step,
+    answer: Option<String>,
+    error: Option<String>,
+    cold_duration: &Duration,
+    stats: Option<&BenchStats>,
+) {
+    let Some(path) = report::get_report_path() else {
+        return;
+    };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let record = Record {
+        day,
+        step,
+        answer,
+        duration: stats.map_or(*cold_duration, |s| s.mean),
+        samples: stats.map_or(1, |s| s.samples),
+        stats: stats.copied(),
+        error,
+    };
+
+    if let Err(e) = report::append(&path, &record) {
+        eprintln!("Failed to write report record: {e}");
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched (approx. 1 second of execution time, bounded by the sample counts configured in `aoc.toml`.)
+///
+/// The duration of the first (cold) execution is always returned, benchmark statistics only when benching
+/// and if the hook, which receives the result of the first execution, returns `true`.
+/// A panic of the first execution is caught and returned as its message.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    hook: impl Fn(&T) -> bool,
+) -> (Result<T, String>, Duration, Option<BenchStats>) {
     let timer = Instant::now();
-    let result = {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
         func(input)
-    };
+    }));
     let base_time = timer.elapsed();
 
-    hook(&result);
+    let result = match result {
+        Ok(result) => result,
+        Err(payload) => return (Err(panic_message(&*payload)), base_time, None),
+    };
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if hook(&result) && std::env::args().any(|x| x == "--time") {
+        Some(bench(func, input, &base_time))
     } else {
-        (base_time, 1)
+        None
     };
 
-    (result, run.0, run.1)
+    (Ok(result), base_time, stats)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// The message a panic was started with, e.g. via `panic!` or `unwrap()`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let config = config::get();
+    let bench_iterations = (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10))
+        .clamp(config.bench_min_samples, config.bench_max_samples);
+
+    let warmup_iterations = get_warmup_iterations().unwrap_or(bench_iterations / 10);
+
+    for _ in 0..warmup_iterations {
+        black_box(func(black_box(input)));
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -67,19 +244,94 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
+}
+
+/// Read the number of warmup iterations from the `--warmup <n>` argument, if passed.
+fn get_warmup_iterations() -> Option<u128> {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == "--warmup")? + 1;
+
+    match args.get(index).map(|x| x.parse::<u128>()) {
+        Some(Ok(iterations)) => Some(iterations),
+        _ => {
+            eprintln!("Unexpected command-line input. Format: --warmup 100");
+            process::exit(1);
+        }
+    }
+}
+
+/// Summary statistics of the samples collected while benching a solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    /// Number of samples outside of the Tukey fences (1.5 × IQR below the first or above the third quartile).
+    pub outliers: usize,
+}
+
+impl BenchStats {
+    /// Compute statistics for a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        nanos.sort_unstable();
+
+        let len = nanos.len() as u128;
+        let mean = nanos.iter().sum::<u128>() / len;
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = nanos
+            .iter()
+            .map(|&x| (x as f64 - mean as f64).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let iqr = q3 - q1;
+        let lower_fence = q1.saturating_sub(iqr * 3 / 2);
+        let upper_fence = q3 + iqr * 3 / 2;
+
+        Self {
+            samples: len,
+            mean: duration_from_nanos(mean),
+            median: duration_from_nanos(percentile(&nanos, 50.0)),
+            min: duration_from_nanos(nanos[0]),
+            max: duration_from_nanos(nanos[nanos.len() - 1]),
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            p95: duration_from_nanos(percentile(&nanos, 95.0)),
+            outliers: nanos
+                .iter()
+                .filter(|&&x| x < lower_fence || x > upper_fence)
+                .count(),
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted, non-empty values.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+fn percentile(sorted: &[u128], percent: f64) -> u128 {
+    let rank = percent / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let weight = rank - rank.floor();
+
+    (sorted[lower] as f64 + (sorted[upper] as f64 - sorted[lower] as f64) * weight).round() as u128
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn duration_from_nanos(nanos: u128) -> Duration {
+    #[allow(clippy::cast_possible_truncation)]
+    Duration::from_nanos(nanos as u64)
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -90,13 +342,24 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_stats(stats: &BenchStats, cold_duration: &Duration) {
+    println!(
+        "  {ANSI_ITALIC}median {:.1?} · min {:.1?} · max {:.1?} · σ {:.1?} · p95 {:.1?} · {} outliers · cold {:.1?}{ANSI_RESET}",
+        stats.median, stats.min, stats.max, stats.std_dev, stats.p95, stats.outliers, cold_duration
+    );
+}
+
+fn print_result<T: Display>(result: &Result<Option<T>, String>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+        Ok(Some(result)) => {
+            let result = result.to_string();
+            if result.contains('\n') {
+                let decoded = ocr::decode(&result)
+                    .map(|text| format!("{ANSI_BOLD}{text}{ANSI_RESET}"))
+                    .unwrap_or_default();
+                let str = format!("{part}: ▼ {decoded}{duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
                 } else {
@@ -114,7 +377,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -122,21 +385,30 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(error) => {
+            if is_intermediate_result {
+                print!("{part}: ✖");
+            } else {
+                print!("\r");
+                println!("{part}: ✖ {error}{duration_str}");
+            }
+        }
     }
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. aoc-cli is installed, or the `native-client` feature is enabled.
+///
+/// Answers drawn as ASCII-art letters are submitted as the decoded letters.
+/// Answers that are known to be wrong from earlier submissions are refused, as are answers
+/// computed from any input other than the official one or with overridden parameters.
+/// Verdicts are recorded in the submission history, accepted answers in the answers manifest.
+fn submit_result<T: Display>(result: T, year: Year, day: Day, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,8 +424,62 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
+    }
+
+    if !Source::from_args(&args).is_ok_and(|x| x.is_official()) {
+        eprintln!("Refusing to submit: the answer was not computed from the official input.");
+        return;
+    }
+
+    if !params_from_args(&args).is_ok_and(|x| x.is_empty()) {
+        eprintln!("Refusing to submit: the answer was computed with overridden parameters.");
+        return;
+    }
+
+    let mut result = result.to_string();
+    if result.contains('\n') {
+        let Some(text) = ocr::decode(&result) else {
+            eprintln!(
+                "Refusing to submit: the letters of the multi-line answer could not be decoded."
+            );
+            return;
+        };
+        result = text;
+    }
+
+    let mut submissions = Submissions::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read submission history: {e}");
+        process::exit(1);
+    });
+
+    if let Err(reason) = submissions.check(day, part, &result) {
+        eprintln!("Refusing to submit: {reason}");
+        return;
+    }
+
+    let Some(verdict) = submit(year, day, part, &result).and_then(|x| Verdict::from_message(&x))
+    else {
+        return;
+    };
+
+    submissions.push(day, part, &result, verdict);
+    if let Err(e) = submissions.store_file(year) {
+        eprintln!("Failed to store submission history: {e}");
+    }
+
+    if verdict == Verdict::Correct {
+        match answers::store_accepted(year, day, part, &result) {
+            Ok(()) => println!("Stored accepted answer in the answers manifest."),
+            Err(e) => eprintln!("Failed to store accepted answer: {e}"),
+        }
     }
+}
+
+/// Submit an answer, returning the message of the server.
+#[cfg(not(feature = "native-client"))]
+fn submit(year: Year, day: Day, part: u8, result: &str) -> Option<String> {
+    use crate::template::aoc_cli;
 
     if aoc_cli::check().is_err() {
         eprintln!(
@@ -163,5 +489,92 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    aoc_cli::submit(year, day, part, result)
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Submit an answer, returning the message of the server.
+#[cfg(feature = "native-client")]
+fn submit(year: Year, day: Day, part: u8, result: &str) -> Option<String> {
+    use crate::template::aoc_client;
+
+    println!("Submitting result...");
+    aoc_client::submit(year, day, part, result)
+        .map_err(|e| eprintln!("failed to submit: {e}"))
+        .ok()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{BenchStats, PartOutput, run_timed};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn computes_stats_for_odd_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[50, 10, 30, 20, 40]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.std_dev, Duration::from_nanos(14));
+        assert_eq!(stats.p95, Duration::from_nanos(48));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn interpolates_median_for_even_samples() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 20, 30, 40]));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+    }
+
+    #[test]
+    fn counts_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 11, 500]));
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(500));
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42]));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn converts_part_outputs() {
+        assert_eq!(Some(42).into_answer(), Ok(Some(42)));
+        assert_eq!(None::<u32>.into_answer(), Ok(None));
+        assert_eq!(Ok::<u32, String>(42).into_answer(), Ok(Some(42)));
+        assert_eq!(
+            Err::<u32, _>("invalid input").into_answer(),
+            Err("invalid input".to_string())
+        );
+    }
+
+    #[test]
+    fn catches_panics() {
+        let (result, _, stats) = run_timed(
+            |input: &str| -> u32 { input.parse().expect("not a number") },
+            "abc",
+            |_| true,
+        );
+        assert_eq!(
+            result,
+            Err("not a number: ParseIntError { kind: InvalidDigit }".into())
+        );
+        assert_eq!(stats, None);
+
+        let (result, _, _) = run_timed(|input: &str| input.len(), "abc", |_| true);
+        assert_eq!(result, Ok(3));
+    }
 }
@@ -0,0 +1,324 @@
+/// Local history of submitted answers, used to refuse submissions that are known to be wrong.
+/// Stored per year as JSON that maps days to the submissions of their parts,
+/// e.g. `{ "01": { "part_1": [{ "answer": "42", "verdict": "too_high" }] } }`.
+use std::{
+    collections::BTreeMap, collections::HashMap, fmt::Display, fs, io::Error, path::PathBuf,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year, config};
+
+fn get_file_path(year: Year) -> PathBuf {
+    config::get().year_dir(year).join("submissions.json")
+}
+
+/// The verdict of the server for a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Verdict {
+    /// Read the verdict from the message returned by the server.
+    /// Returns `None` if the answer was not judged, e.g. when submitting too quickly.
+    pub fn from_message(message: &str) -> Option<Self> {
+        if message.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if message.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if message.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if message.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Submitted answers, keyed by day and part, in the order they were submitted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Submissions {
+    pub data: BTreeMap<(Day, u8), Vec<Submission>>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(get_file_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty history.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_file_path(year)) {
+            Ok(contents) => Submissions::try_from(contents),
+            Err(_) => Ok(Submissions::default()),
+        }
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> &[Submission] {
+        self.data.get(&(day, part)).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn push(&mut self, day: Day, part: u8, answer: &str, verdict: Verdict) {
+        self.data.entry((day, part)).or_default().push(Submission {
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+
+    /// Check whether an answer is worth submitting. Returns the reason if it is not.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), String> {
+        let submissions = self.get(day, part);
+
+        if let Some(previous) = submissions.iter().find(|x| x.answer == answer) {
+            return Err(match previous.verdict {
+                Verdict::Correct => format!("`{answer}` was already accepted."),
+                Verdict::Wrong => format!("`{answer}` was already submitted and is wrong."),
+                Verdict::TooHigh => format!("`{answer}` was already submitted and is too high."),
+                Verdict::TooLow => format!("`{answer}` was already submitted and is too low."),
+            });
+        }
+
+        if let Some(correct) = submissions.iter().find(|x| x.verdict == Verdict::Correct) {
+            return Err(format!(
+                "part {part} was already solved with `{}`.",
+                correct.answer
+            ));
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            submissions
+                .iter()
+                .filter(move |x| x.verdict == verdict)
+                .filter_map(|x| x.answer.parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(Verdict::TooHigh).min()
+            && value >= upper
+        {
+            return Err(format!(
+                "`{answer}` is not below `{upper}`, which is too high."
+            ));
+        }
+
+        if let Some(lower) = bound(Verdict::TooLow).max()
+            && value <= lower
+        {
+            return Err(format!(
+                "`{answer}` is not above `{lower}`, which is too low."
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submissions> for JsonValue {
+    fn from(value: &Submissions) -> Self {
+        let mut days: HashMap<String, JsonValue> = HashMap::new();
+
+        for ((day, part), submissions) in &value.data {
+            let entry = days
+                .entry(day.to_string())
+                .or_insert_with(|| JsonValue::Object(HashMap::new()));
+
+            let submissions = submissions
+                .iter()
+                .map(|submission| {
+                    let mut map: HashMap<String, JsonValue> = HashMap::new();
+                    map.insert(
+                        "answer".into(),
+                        JsonValue::String(submission.answer.clone()),
+                    );
+                    map.insert(
+                        "verdict".into(),
+                        JsonValue::String(submission.verdict.to_string()),
+                    );
+                    JsonValue::Object(map)
+                })
+                .collect();
+
+            if let JsonValue::Object(parts) = entry {
+                parts.insert(format!("part_{part}"), JsonValue::Array(submissions));
+            }
+        }
+
+        JsonValue::Object(days)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let days = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut data = BTreeMap::new();
+
+        for (day_str, parts) in days {
+            let day = Day::from_str(day_str).map_err(|e| format!("submissions.{day_str}: {e}."))?;
+
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("expected submissions.{day_str} to be an object."))?;
+
+            for (part_str, submissions) in parts {
+                let part = match part_str.as_str() {
+                    "part_1" => 1,
+                    "part_2" => 2,
+                    _ => return Err(format!("unexpected key submissions.{day_str}.{part_str}.")),
+                };
+
+                let submissions = submissions
+                    .get::<Vec<JsonValue>>()
+                    .ok_or(format!(
+                        "expected submissions.{day_str}.{part_str} to be an array."
+                    ))?
+                    .iter()
+                    .map(|submission| {
+                        let submission = submission
+                            .get::<HashMap<String, JsonValue>>()
+                            .ok_or("expected submission to be an object.")?;
+                        let answer = submission
+                            .get("answer")
+                            .and_then(|v| v.get::<String>())
+                            .ok_or("expected submission.answer to be a string.")?;
+                        let verdict = submission
+                            .get("verdict")
+                            .and_then(|v| v.get::<String>())
+                            .ok_or("expected submission.verdict to be a string.")?
+                            .parse()?;
+
+                        Ok(Submission {
+                            answer: answer.clone(),
+                            verdict,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+
+                data.insert((day, part), submissions);
+            }
+        }
+
+        Ok(Submissions { data })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{Submissions, Verdict};
+    use crate::day;
+
+    fn get_mock_submissions() -> Submissions {
+        let mut submissions = Submissions::default();
+        submissions.push(day!(1), 1, "100", Verdict::TooHigh);
+        submissions.push(day!(1), 1, "20", Verdict::TooLow);
+        submissions.push(day!(1), 1, "60", Verdict::TooHigh);
+        submissions.push(day!(1), 1, "50", Verdict::Wrong);
+        submissions.push(day!(2), 2, "abc", Verdict::Correct);
+        submissions
+    }
+
+    #[test]
+    fn round_trips_submissions() {
+        let submissions = get_mock_submissions();
+        let json = JsonValue::from(&submissions).stringify().unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap(), submissions);
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let submissions = get_mock_submissions();
+        assert!(submissions.check(day!(1), 1, "50").is_err());
+        assert!(submissions.check(day!(2), 2, "abc").is_err());
+        assert!(submissions.check(day!(2), 2, "def").is_err());
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let submissions = get_mock_submissions();
+        assert!(submissions.check(day!(1), 1, "60").is_err());
+        assert!(submissions.check(day!(1), 1, "70").is_err());
+        assert!(submissions.check(day!(1), 1, "15").is_err());
+        assert!(submissions.check(day!(1), 1, "21").is_ok());
+        assert!(submissions.check(day!(1), 1, "59").is_ok());
+        assert!(submissions.check(day!(1), 1, "not a number").is_ok());
+        assert!(submissions.check(day!(1), 2, "1000").is_ok());
+    }
+
+    #[test]
+    fn reads_verdicts_from_messages() {
+        assert_eq!(
+            Verdict::from_message("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer; your answer is too high."),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer. If you're stuck, ..."),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::from_message("You gave an answer too recently."),
+            None
+        );
+    }
+}
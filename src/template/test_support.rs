@@ -0,0 +1,24 @@
+/// Fixtures shared by the tests of the template modules.
+use std::time::Duration;
+
+use crate::template::Day;
+use crate::template::report::{Record, Step};
+
+/// A record of a successful run, for the tests of the modules that consume records.
+pub fn mock_record(
+    day: Day,
+    step: Step,
+    answer: Option<&str>,
+    nanos: u64,
+    samples: u128,
+) -> Record {
+    Record {
+        day,
+        step,
+        answer: answer.map(Into::into),
+        duration: Duration::from_nanos(nanos),
+        samples,
+        stats: None,
+        error: None,
+    }
+}
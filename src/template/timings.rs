@@ -1,19 +1,74 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::report::{Record, Step, insert_stats, read_stats};
+use crate::template::runner::BenchStats;
+use crate::template::{Day, Year, config};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Version of the `timings.json` schema. Files of older versions are migrated when read.
+///  1. steps are stored as formatted strings, e.g. `"74.13ns"`.
+///  2. steps are stored as numeric nanoseconds, along with their benchmark statistics.
+const TIMINGS_SCHEMA_VERSION: u32 = 2;
+
+fn get_file_path(year: Year) -> PathBuf {
+    config::get().year_dir(year).join("timings.json")
+}
+
+/// Benchmark time of a single step of a solution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StepTiming {
+    /// Mean execution time.
+    pub duration: Duration,
+    /// Distribution of the samples. Not present for timings migrated from schema version 1.
+    pub stats: Option<BenchStats>,
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
+    pub parse: Option<StepTiming>,
+    pub part_1: Option<StepTiming>,
+    pub part_2: Option<StepTiming>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// Build the timing of a day from the records its solution binary reported.
+    /// Only benched steps are considered, unsolved parts are left empty.
+    pub fn from_records(day: Day, records: &[Record]) -> Self {
+        let mut timing = Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            total_nanos: 0_f64,
+        };
+
+        records
+            .iter()
+            .filter(|r| r.day == day && r.is_benched())
+            .for_each(|r| {
+                let step_timing = Some(StepTiming {
+                    duration: r.duration,
+                    stats: r.stats,
+                });
+                match r.step {
+                    Step::Parse => timing.parse = step_timing,
+                    Step::Part(1) => timing.part_1 = step_timing,
+                    Step::Part(2) => timing.part_2 = step_timing,
+                    Step::Part(_) => return,
+                }
+                #[allow(clippy::cast_precision_loss)]
+                {
+                    timing.total_nanos += r.duration.as_nanos() as f64;
+                }
+            });
+
+        timing
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -23,15 +78,15 @@ pub struct Timings {
 
 impl Timings {
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_file_path(year))?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_file_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +106,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -60,6 +115,17 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
+    /// The stored timing of a step of a day, if it was benched.
+    pub fn step(&self, day: Day, step: Step) -> Option<&StepTiming> {
+        let timing = self.data.iter().find(|t| t.day == day)?;
+        match step {
+            Step::Parse => timing.parse.as_ref(),
+            Step::Part(1) => timing.part_1.as_ref(),
+            Step::Part(2) => timing.part_2.as_ref(),
+            Step::Part(_) => None,
+        }
+    }
+
     pub fn is_day_complete(&self, day: Day) -> bool {
         self.data
             .iter()
@@ -69,10 +135,32 @@ impl Timings {
 
 /* -------------------------------------------------------------------------- */
 
+/// Parse a duration formatted by its `Debug` implementation, e.g. `74.13ns` or `1.2ms`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let split_at = s.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = s.split_at(split_at);
+    let value: f64 = value.parse().ok()?;
+
+    let nanos = match unit {
+        "ns" => value,
+        "µs" => value * 1e3,
+        "ms" => value * 1e6,
+        "s" => value * 1e9,
+        _ => return None,
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some(Duration::from_nanos(nanos.round() as u64))
+}
+
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(TIMINGS_SCHEMA_VERSION)),
+        );
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -88,9 +176,25 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: files written before the schema was versioned do not have the key.
+        let version = match json.get("version") {
+            None => 1,
+            Some(v) => *v
+                .get::<f64>()
+                .ok_or("expected `json.version` to be a number.")? as u32,
+        };
+
+        if version > TIMINGS_SCHEMA_VERSION {
+            return Err(format!(
+                "unsupported schema version {version}, expecting at most {TIMINGS_SCHEMA_VERSION}."
+            ));
+        }
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
@@ -99,7 +203,7 @@ impl TryFrom<String> for Timings {
         Ok(Timings {
             data: json_data
                 .iter()
-                .map(Timing::try_from)
+                .map(|timing| Timing::from_json(timing, version))
                 .collect::<Result<_, _>>()?,
         })
     }
@@ -107,6 +211,58 @@ impl TryFrom<String> for Timings {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&StepTiming> for JsonValue {
+    fn from(value: &StepTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+
+        if let Some(stats) = &value.stats {
+            insert_stats(&mut map, stats);
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl StepTiming {
+    /// Read a step timing of the given schema version.
+    /// Version 1 stored the formatted mean only, e.g. `"74.13ns"`.
+    fn from_json(value: &JsonValue, version: u32, key: &str) -> Result<Self, String> {
+        if version == 1 {
+            let duration = value
+                .get::<String>()
+                .and_then(|s| parse_duration(s))
+                .ok_or(format!("Expected timing.{key} to be a formatted duration."))?;
+            return Ok(StepTiming {
+                duration,
+                stats: None,
+            });
+        }
+
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or(format!("Expected timing.{key} to be null or an object."))?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or(format!("Expected timing.{key}.nanos to be a number."))?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = Duration::from_nanos(nanos as u64);
+
+        Ok(StepTiming {
+            duration,
+            stats: read_stats(json, duration)?,
+        })
+    }
+}
+
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
@@ -114,33 +270,23 @@ impl From<&Timing> for JsonValue {
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
-
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
-
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        for (key, step) in [
+            ("parse", &value.parse),
+            ("part_1", &value.part_1),
+            ("part_2", &value.part_2),
+        ] {
+            map.insert(
+                key.into(),
+                step.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
 
         JsonValue::Object(map)
     }
 }
 
-impl TryFrom<&JsonValue> for Timing {
-    type Error = String;
-
-    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+impl Timing {
+    fn from_json(value: &JsonValue, version: u32) -> Result<Self, String> {
         let json = value
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
@@ -151,15 +297,22 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let step = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => StepTiming::from_json(v, version, key).map(Some),
+        };
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        // NOTE: `parse` was added later, timings stored before that do not have the key.
+        let parse = step("parse")?;
+
+        // parts are always written, even if they were not benched.
+        for key in ["part_1", "part_2"] {
+            if !json.contains_key(key) {
+                return Err(format!("Expected timing.{key} to be present."));
+            }
+        }
+        let part_1 = step("part_1")?;
+        let part_2 = step("part_2")?;
 
         let total_nanos = json
             .get("total_nanos")
@@ -168,8 +321,9 @@ impl TryFrom<&JsonValue> for Timing {
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
+            parse,
+            part_1,
+            part_2,
             total_nanos,
         })
     }
@@ -179,28 +333,40 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
+    use std::time::Duration;
+
     use crate::day;
 
-    use super::{Timing, Timings};
+    use super::{StepTiming, Timing, Timings};
+
+    fn step(millis: u64) -> Option<StepTiming> {
+        Some(StepTiming {
+            duration: Duration::from_millis(millis),
+            stats: None,
+        })
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
+                    parse: None,
+                    part_1: step(10),
+                    part_2: step(20),
                     total_nanos: 3e+10,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
+                    parse: None,
+                    part_1: step(30),
+                    part_2: step(40),
                     total_nanos: 7e+10,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    parse: None,
+                    part_1: step(40),
                     part_2: None,
                     total_nanos: 4e+10,
                 },
@@ -209,23 +375,52 @@ mod tests {
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{runner::BenchStats, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "parse": null, "part_1": { "nanos": 1000000 }, "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(
+                timing.part_1.as_ref().unwrap().duration,
+                Duration::from_millis(1)
+            );
+            assert_eq!(timing.part_1.as_ref().unwrap().stats, None);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos, 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_json_timings_with_stats() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "parse": null, "part_1": { "nanos": 1500, "samples": 100, "median": 1400, "min": 1000, "max": 5000, "std_dev": 200, "p95": 2000, "outliers": 2 }, "part_2": null, "total_nanos": 1500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let stats = timings.data[0].part_1.as_ref().unwrap().stats.unwrap();
+            assert_eq!(
+                stats,
+                BenchStats {
+                    samples: 100,
+                    mean: Duration::from_nanos(1500),
+                    median: Duration::from_nanos(1400),
+                    min: Duration::from_nanos(1000),
+                    max: Duration::from_nanos(5000),
+                    std_dev: Duration::from_nanos(200),
+                    p95: Duration::from_nanos(2000),
+                    outliers: 2,
+                }
+            );
         }
 
         #[test]
         fn handles_empty_timings() {
-            let json = r#"{ "data": [] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 0);
         }
@@ -243,10 +438,65 @@ mod tests {
             let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unsupported_versions() {
+            let json = r#"{ "version": 3, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+    }
+
+    mod migration {
+        use std::time::Duration;
+
+        use crate::{day, template::timings::Timings};
+
+        #[test]
+        fn migrates_formatted_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1.5ms", "part_2": null, "total_nanos": 1500000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.parse, None);
+            assert_eq!(
+                timing.part_1.as_ref().unwrap().duration,
+                Duration::from_micros(1500)
+            );
+            assert_eq!(timing.part_1.as_ref().unwrap().stats, None);
+            assert_eq!(timing.part_2, None);
+        }
+
+        #[test]
+        fn migrates_formatted_timings_with_parse() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "2.0µs", "part_1": "74.13ns", "part_2": "1.2s", "total_nanos": 1200002074 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(
+                timing.parse.as_ref().unwrap().duration,
+                Duration::from_micros(2)
+            );
+            assert_eq!(
+                timing.part_1.as_ref().unwrap().duration,
+                Duration::from_nanos(74)
+            );
+            assert_eq!(
+                timing.part_2.as_ref().unwrap().duration,
+                Duration::from_millis(1200)
+            );
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_formatted_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1 minute", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
     }
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::Timings;
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -254,11 +504,10 @@ mod tests {
         fn serializes_timings() {
             let timings = get_mock_timings();
             let value = JsonValue::try_from(timings).unwrap();
+            let json = value.get::<HashMap<String, JsonValue>>().unwrap();
+            assert_eq!(json.get("version"), Some(&JsonValue::Number(2.0)));
             assert_eq!(
-                value
-                    .get::<HashMap<String, JsonValue>>()
-                    .unwrap()
-                    .get("data")
+                json.get("data")
                     .unwrap()
                     .get::<Vec<JsonValue>>()
                     .unwrap()
@@ -266,6 +515,72 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn round_trips_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data[0].part_1, timings.data[0].part_1);
+            assert_eq!(parsed.data[2].part_2, None);
+        }
+    }
+
+    mod from_records {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{report::Step, test_support::mock_record, timings::Timing},
+        };
+
+        #[test]
+        fn handles_benched_records() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    mock_record(day!(1), Step::Parse, None, 10_500, 1000),
+                    mock_record(day!(1), Step::Part(1), Some("0"), 74, 100_000),
+                    mock_record(
+                        day!(1),
+                        Step::Part(2),
+                        Some("a @ (b samples)"),
+                        74_130_000,
+                        99_999,
+                    ),
+                ],
+            );
+            assert_eq!(timing.parse.unwrap().duration, Duration::from_nanos(10_500));
+            assert_eq!(timing.part_1.unwrap().duration, Duration::from_nanos(74));
+            assert_eq!(
+                timing.part_2.unwrap().duration,
+                Duration::from_nanos(74_130_000)
+            );
+            assert_eq!(timing.total_nanos, 74_140_574_f64);
+        }
+
+        #[test]
+        fn handles_missing_parts() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[
+                    mock_record(day!(1), Step::Part(1), None, 100, 10),
+                    mock_record(day!(1), Step::Part(2), None, 100, 10),
+                ],
+            );
+            assert_eq!(timing.part_1, None);
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos, 0_f64);
+        }
+
+        #[test]
+        fn ignores_untimed_records() {
+            let timing = Timing::from_records(
+                day!(1),
+                &[mock_record(day!(1), Step::Part(1), Some("1"), 100, 1)],
+            );
+            assert_eq!(timing.part_1, None);
+        }
     }
 
     mod is_day_complete {
@@ -274,18 +589,21 @@ mod tests {
             template::timings::{Timing, Timings},
         };
 
+        use super::step;
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
+                    parse: None,
+                    part_1: step(1),
+                    part_2: step(2),
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +611,14 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    parse: None,
+                    part_1: step(1),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -307,13 +626,14 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -331,6 +651,7 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
@@ -351,6 +672,7 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
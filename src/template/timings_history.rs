@@ -0,0 +1,381 @@
+/// History of stored benchmarks, used by `cargo time --compare` to detect regressions.
+///
+/// Every `cargo time --store` appends the benched records of the run as JSON lines, each stamped
+/// with the time of the run and the git commit it was run against.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::report::{Record, Step};
+use crate::template::timings::Timings;
+use crate::template::{Day, Year, config};
+
+fn get_file_path(year: Year) -> PathBuf {
+    config::get().year_dir(year).join("timings_history.jsonl")
+}
+
+/// A benched record of a stored run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// The commit the run was benched against, suffixed with `-dirty` if there were local changes.
+    pub commit: Option<String>,
+    pub record: Record,
+}
+
+/// The stored timing a new run is compared against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Baseline {
+    /// The most recently stored timing.
+    #[default]
+    Last,
+    /// The fastest timing ever stored.
+    Best,
+}
+
+impl FromStr for Baseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "last" => Ok(Baseline::Last),
+            "best" => Ok(Baseline::Best),
+            _ => Err(format!(
+                "unknown baseline `{s}`, expecting `last` or `best`."
+            )),
+        }
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Baseline::Last => "last",
+            Baseline::Best => "best",
+        })
+    }
+}
+
+/// The timing of a step compared against its baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    pub step: Step,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change in percent, positive if the step got slower.
+    pub change: f64,
+    pub is_regression: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    /// Rehydrate the history from its file. If not present, returns an empty history.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_file_path(year)) {
+            Ok(contents) => parse_lines(&contents),
+            Err(_) => Ok(History::default()),
+        }
+    }
+
+    /// Append the benched records of a run to the history file, stamped with the current time and commit.
+    pub fn append_to_file(year: Year, records: &[Record]) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_secs());
+        let commit = get_commit();
+
+        let mut lines = String::new();
+
+        for record in records.iter().filter(|r| r.is_benched()) {
+            let entry = Entry {
+                timestamp,
+                commit: commit.clone(),
+                record: record.clone(),
+            };
+            let line = JsonValue::from(&entry)
+                .stringify()
+                .map_err(|e| io::Error::other(e.to_string()))?;
+            lines.push_str(&line);
+            lines.push('\n');
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(get_file_path(year))?;
+        file.write_all(lines.as_bytes())
+    }
+
+    /// The stored timing of a step, if it was benched before.
+    pub fn baseline(&self, day: Day, step: Step, baseline: Baseline) -> Option<Duration> {
+        let mut durations = self
+            .entries
+            .iter()
+            .filter(|e| e.record.day == day && e.record.step == step)
+            .map(|e| e.record.duration);
+
+        match baseline {
+            Baseline::Last => durations.next_back(),
+            Baseline::Best => durations.min(),
+        }
+    }
+
+    /// Compare the benched records of a run against the history.
+    /// A step regressed if it is more than `threshold` percent slower than its baseline.
+    ///
+    /// Steps without history fall back to `stored`, the timings of `timings.json`, which were
+    /// stored without a history before it was introduced. This applies to either baseline.
+    pub fn compare(
+        &self,
+        records: &[Record],
+        stored: &Timings,
+        baseline: Baseline,
+        threshold: f64,
+    ) -> Vec<Comparison> {
+        records
+            .iter()
+            .filter(|r| r.is_benched())
+            .filter_map(|r| {
+                let stored = self
+                    .baseline(r.day, r.step, baseline)
+                    .or_else(|| stored.step(r.day, r.step).map(|x| x.duration))?;
+                let change = if stored.is_zero() {
+                    0.0
+                } else {
+                    (r.duration.as_secs_f64() / stored.as_secs_f64() - 1.0) * 100.0
+                };
+
+                Some(Comparison {
+                    day: r.day,
+                    step: r.step,
+                    baseline: stored,
+                    current: r.duration,
+                    change,
+                    is_regression: change > threshold,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The current commit, or `None` if not run inside a git repository.
+fn get_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        None
+    }
+}
+
+fn parse_lines(contents: &str) -> Result<History, String> {
+    let entries = contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("not a valid JSON line."))?;
+            Entry::try_from(&json)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(History { entries })
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Entry> for JsonValue {
+    fn from(value: &Entry) -> Self {
+        let mut json = JsonValue::from(&value.record);
+
+        if let JsonValue::Object(map) = &mut json {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert(
+                "timestamp".into(),
+                JsonValue::Number(value.timestamp as f64),
+            );
+            map.insert(
+                "commit".into(),
+                value
+                    .commit
+                    .clone()
+                    .map_or(JsonValue::Null, JsonValue::String),
+            );
+        }
+
+        json
+    }
+}
+
+impl TryFrom<&JsonValue> for Entry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let record = Record::try_from(value)?;
+
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Entry {
+            timestamp: timestamp as u64,
+            commit: commit.cloned(),
+            record,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Baseline, Entry, History, parse_lines};
+    use crate::day;
+    use crate::template::report::{Record, Step};
+    use crate::template::test_support::mock_record;
+    use crate::template::timings::{StepTiming, Timing, Timings};
+
+    fn get_mock_history() -> History {
+        let entry = |timestamp: u64, record: Record| Entry {
+            timestamp,
+            commit: Some("abc1234".into()),
+            record,
+        };
+
+        History {
+            entries: vec![
+                entry(1, mock_record(day!(1), Step::Part(1), Some("1"), 1000, 100)),
+                entry(1, mock_record(day!(1), Step::Part(2), Some("1"), 5000, 100)),
+                entry(2, mock_record(day!(1), Step::Part(1), Some("1"), 800, 100)),
+                entry(3, mock_record(day!(1), Step::Part(1), Some("1"), 900, 100)),
+            ],
+        }
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let history = get_mock_history();
+        let lines = history
+            .entries
+            .iter()
+            .map(|e| JsonValue::from(e).stringify().unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(parse_lines(&lines).unwrap(), history);
+    }
+
+    #[test]
+    fn selects_baselines() {
+        let history = get_mock_history();
+        assert_eq!(
+            history.baseline(day!(1), Step::Part(1), Baseline::Last),
+            Some(Duration::from_nanos(900))
+        );
+        assert_eq!(
+            history.baseline(day!(1), Step::Part(1), Baseline::Best),
+            Some(Duration::from_nanos(800))
+        );
+        assert_eq!(
+            history.baseline(day!(2), Step::Part(1), Baseline::Last),
+            None
+        );
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let history = get_mock_history();
+        let records = vec![
+            mock_record(day!(1), Step::Part(1), Some("1"), 950, 100),
+            mock_record(day!(1), Step::Part(2), Some("1"), 6000, 100),
+            mock_record(day!(2), Step::Part(1), Some("1"), 100, 100),
+        ];
+
+        let comparisons = history.compare(&records, &Timings::default(), Baseline::Last, 10.0);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].is_regression);
+        assert!(comparisons[1].is_regression);
+        assert!((comparisons[1].change - 20.0).abs() < 1e-9);
+
+        let comparisons = history.compare(&records, &Timings::default(), Baseline::Best, 10.0);
+        assert!(comparisons[0].is_regression);
+    }
+
+    #[test]
+    fn ignores_unbenched_records() {
+        let history = get_mock_history();
+        let mut slow = mock_record(day!(1), Step::Part(1), Some("1"), 1_000_000, 100);
+        slow.samples = 1;
+        assert!(
+            history
+                .compare(&[slow], &Timings::default(), Baseline::Last, 10.0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn falls_back_to_stored_timings() {
+        let stored = Timings {
+            data: vec![Timing {
+                day: day!(2),
+                parse: None,
+                part_1: Some(StepTiming {
+                    duration: Duration::from_nanos(100),
+                    stats: None,
+                }),
+                part_2: None,
+                total_nanos: 100.0,
+            }],
+        };
+        let records = vec![
+            mock_record(day!(1), Step::Part(1), Some("1"), 950, 100),
+            mock_record(day!(2), Step::Part(1), Some("1"), 150, 100),
+        ];
+
+        let comparisons = History::default().compare(&records, &stored, Baseline::Last, 10.0);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].day, day!(2));
+        assert!(comparisons[0].is_regression);
+
+        let comparisons = get_mock_history().compare(&records, &stored, Baseline::Last, 10.0);
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].baseline, Duration::from_nanos(900));
+
+        let comparisons = History::default().compare(&records, &stored, Baseline::Best, 10.0);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].day, day!(2));
+        assert_eq!(comparisons[0].baseline, Duration::from_nanos(100));
+    }
+}
@@ -0,0 +1,206 @@
+/// Building blocks of `cargo solve --watch`: change detection by polling and comparing answers between runs.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::template::report::{Record, Step};
+
+/// Modification times of a set of watched files. Missing files are watched as well, so that
+/// creating them counts as a change.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Self {
+        let files = paths
+            .iter()
+            .map(|path| {
+                let modified = fs::metadata(path).and_then(|x| x.modified()).ok();
+                (path.clone(), modified)
+            })
+            .collect();
+
+        Snapshot { files }
+    }
+
+    /// The files that were created, modified or deleted since the other snapshot.
+    pub fn changes_since(&self, other: &Snapshot) -> Vec<&PathBuf> {
+        self.files
+            .iter()
+            .filter(|(path, modified)| {
+                other
+                    .files
+                    .iter()
+                    .find(|(x, _)| x == path)
+                    .is_none_or(|(_, x)| x != modified)
+            })
+            .map(|(path, _)| path)
+            .collect()
+    }
+}
+
+/// Every Rust file below `src`, except the ones in its `bin` folder, which hold the solutions of
+/// other days. A day depends on all of them through the library and the template.
+pub fn source_files(src: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![src.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+
+        for path in entries.filter_map(|x| x.ok()).map(|x| x.path()) {
+            if path.is_dir() {
+                if path != src.join("bin") {
+                    dirs.push(path);
+                }
+            } else if path.extension().is_some_and(|x| x == "rs") {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+/// The answer of a part compared against the previous run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerChange {
+    /// The part was not run before.
+    New(Option<String>),
+    Unchanged(Option<String>),
+    Changed {
+        previous: Option<String>,
+        current: Option<String>,
+    },
+    /// The part was run before, but did not report this time, e.g. because it panicked.
+    Missing(Option<String>),
+}
+
+/// Compare the answers of both parts between two runs.
+pub fn diff_answers(previous: Option<&[Record]>, current: &[Record]) -> Vec<(u8, AnswerChange)> {
+    let find = |records: &[Record], part: u8| {
+        records
+            .iter()
+            .find(|r| r.step == Step::Part(part))
+            .map(|r| r.answer.clone())
+    };
+
+    [1, 2]
+        .into_iter()
+        .filter_map(|part| {
+            let before = previous.map(|records| find(records, part));
+            let after = find(current, part);
+
+            let change = match (before, after) {
+                (None | Some(None), None) => return None,
+                (None | Some(None), Some(current)) => AnswerChange::New(current),
+                (Some(Some(previous)), None) => AnswerChange::Missing(previous),
+                (Some(Some(previous)), Some(current)) if previous == current => {
+                    AnswerChange::Unchanged(current)
+                }
+                (Some(Some(previous)), Some(current)) => {
+                    AnswerChange::Changed { previous, current }
+                }
+            };
+
+            Some((part, change))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{AnswerChange, Snapshot, diff_answers, source_files};
+    use crate::day;
+    use crate::template::report::Step;
+    use crate::template::test_support::mock_record;
+
+    #[test]
+    fn detects_changed_files() {
+        let path = PathBuf::from("file.txt");
+        let before = Snapshot {
+            files: vec![(path.clone(), None)],
+        };
+        let after = Snapshot {
+            files: vec![(path.clone(), Some(std::time::UNIX_EPOCH))],
+        };
+
+        assert_eq!(after.changes_since(&before), vec![&path]);
+        assert!(after.changes_since(&after).is_empty());
+    }
+
+    #[test]
+    fn collects_source_files() {
+        let files = source_files(Path::new("src"));
+
+        assert!(files.contains(&PathBuf::from("src/lib.rs")));
+        assert!(files.contains(&PathBuf::from("src/linear.rs")));
+        assert!(files.contains(&PathBuf::from("src/template/commands/watch.rs")));
+        assert!(!files.iter().any(|x| x.starts_with("src/bin")));
+        assert!(
+            files
+                .iter()
+                .all(|x| x.extension().is_some_and(|x| x == "rs"))
+        );
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let previous = vec![
+            mock_record(day!(1), Step::Part(1), Some("1"), 100, 1),
+            mock_record(day!(1), Step::Part(2), Some("2"), 100, 1),
+        ];
+        let current = vec![
+            mock_record(day!(1), Step::Part(1), Some("1"), 100, 1),
+            mock_record(day!(1), Step::Part(2), Some("3"), 100, 1),
+        ];
+
+        assert_eq!(
+            diff_answers(Some(&previous), &current),
+            vec![
+                (1, AnswerChange::Unchanged(Some("1".into()))),
+                (
+                    2,
+                    AnswerChange::Changed {
+                        previous: Some("2".into()),
+                        current: Some("3".into()),
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn diffs_answers_of_first_and_failed_runs() {
+        let records = vec![
+            mock_record(day!(1), Step::Part(1), Some("1"), 100, 1),
+            mock_record(day!(1), Step::Part(2), None, 100, 1),
+        ];
+
+        assert_eq!(
+            diff_answers(None, &records),
+            vec![
+                (1, AnswerChange::New(Some("1".into()))),
+                (2, AnswerChange::New(None)),
+            ]
+        );
+        assert_eq!(
+            diff_answers(Some(&records), &records[..1]),
+            vec![
+                (1, AnswerChange::Unchanged(Some("1".into()))),
+                (2, AnswerChange::Missing(None)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{Day, config};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// A year of advent, starting with the first event in 2015.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if advent took place in it,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < 2015 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The number of days in the calendar of this year: 25 until 2024, 12 from 2025 on.
+    pub const fn days(self) -> u8 {
+        if self.0 < 2025 { 25 } else { 12 }
+    }
+
+    /// Whether the calendar of this year contains the given day.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.days()
+    }
+
+    /// Reads the default year of the project: the `AOC_YEAR` environment variable if set,
+    /// else the `year` configured in `aoc.toml`.
+    pub fn from_config() -> Option<Self> {
+        match std::env::var("AOC_YEAR") {
+            Ok(year) => year.parse().ok(),
+            Err(_) => config::get().year,
+        }
+    }
+
+    /// Reads the year from the path of a solution file, which is named like `2025-01.rs`.
+    /// Panics if the path does not follow this pattern, which fails the build in a const context.
+    pub const fn from_solution_path(path: &str) -> Self {
+        let bytes = path.as_bytes();
+
+        let mut start = bytes.len();
+        while start > 0 && bytes[start - 1] != b'/' && bytes[start - 1] != b'\\' {
+            start -= 1;
+        }
+
+        assert!(
+            bytes.len() > start + 4 && bytes[start + 4] == b'-',
+            "solution files must be named like `src/bin/2025-01.rs`"
+        );
+
+        let mut year = 0;
+        let mut i = start;
+        while i < start + 4 {
+            assert!(
+                bytes[i].is_ascii_digit(),
+                "solution files must be named like `src/bin/2025-01.rs`"
+            );
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        match Self::new(year) {
+            Some(year) => year,
+            None => panic!("invalid year in solution file name, expecting a year from 2015 on"),
+        }
+    }
+}
+
+#[cfg(feature = "today")]
+impl Year {
+    /// Returns the year of the current date.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        Self::new(u16::try_from(today.year()).ok()?)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year from 2015 on")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value from 2015 on")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::day;
+
+    #[test]
+    fn knows_calendar_lengths() {
+        assert_eq!(year!(2015).days(), 25);
+        assert_eq!(year!(2024).days(), 25);
+        assert_eq!(year!(2025).days(), 12);
+        assert!(year!(2024).has_day(day!(25)));
+        assert!(!year!(2025).has_day(day!(13)));
+    }
+
+    #[test]
+    fn reads_year_from_solution_path() {
+        assert_eq!(Year::from_solution_path("src/bin/2024-05.rs"), year!(2024));
+        assert_eq!(
+            Year::from_solution_path("C:\\aoc\\src\\bin\\2016-25.rs"),
+            year!(2016)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_unnamespaced_solution_path() {
+        Year::from_solution_path("src/bin/05.rs");
+    }
+}
@@ -0,0 +1,160 @@
+//! Disjoint sets of elements `0..n`, e.g. the connected components of a graph that gains edges.
+
+/// The outcome of joining two elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Union {
+    /// Both elements already were in the same component.
+    Same,
+    /// The components of both elements were merged.
+    Merged,
+    /// The components of both elements were merged into the last remaining one.
+    FullyConnected,
+}
+
+/// A union-find structure with path compression and union by size, so that both [`UnionFind::find`]
+/// and [`UnionFind::union`] run in nearly constant amortized time.
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Create `len` components holding a single element each.
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the component of an element.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // point every element on the path directly to the root.
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+
+        root
+    }
+
+    /// Join the components of both elements.
+    pub fn union(&mut self, a: usize, b: usize) -> Union {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return Union::Same;
+        }
+
+        let (larger, smaller) = if self.size[a] >= self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[smaller] = larger;
+        self.size[larger] += self.size[smaller];
+        self.components -= 1;
+
+        if self.components == 1 {
+            Union::FullyConnected
+        } else {
+            Union::Merged
+        }
+    }
+
+    /// Whether both elements are in the same component.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of components.
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// The number of elements in the component of an element.
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// The number of elements of every component, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|x| self.size[x])
+            .collect()
+    }
+
+    /// The sizes of the `k` largest components, largest first.
+    pub fn largest(&self, k: usize) -> Vec<usize> {
+        let mut sizes = self.component_sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.truncate(k);
+        sizes
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Union, UnionFind};
+
+    #[test]
+    fn joins_components() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.component_count(), 6);
+
+        assert_eq!(sets.union(0, 1), Union::Merged);
+        assert_eq!(sets.union(2, 3), Union::Merged);
+        assert_eq!(sets.union(1, 3), Union::Merged);
+        assert_eq!(sets.union(0, 2), Union::Same);
+
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.component_count(), 3);
+        assert_eq!(sets.component_size(2), 4);
+        assert_eq!(sets.largest(2), vec![4, 1]);
+
+        let mut sizes = sets.component_sizes();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 1, 4]);
+    }
+
+    #[test]
+    fn reports_full_connection() {
+        let mut sets = UnionFind::new(3);
+        assert_eq!(sets.union(0, 1), Union::Merged);
+        assert_eq!(sets.union(1, 2), Union::FullyConnected);
+        assert_eq!(sets.union(2, 0), Union::Same);
+        assert_eq!(sets.largest(5), vec![3]);
+    }
+
+    #[test]
+    fn compresses_long_paths() {
+        let mut sets = UnionFind::new(1000);
+        for x in 1..1000 {
+            sets.union(x - 1, x);
+        }
+        let root = sets.find(999);
+        assert!((0..1000).all(|x| sets.find(x) == root));
+        assert_eq!(sets.component_size(0), 1000);
+    }
+}